@@ -1,4 +1,6 @@
 //! JSON parser implementation.
+use somen::parser::grammar::Describe;
+use somen::parser::text::string_literal;
 use somen::{call, prelude::*};
 use std::collections::HashMap;
 
@@ -12,7 +14,7 @@ enum JsonValue {
     String(String),
 }
 
-fn spaces<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = ()> + 'a {
+fn spaces<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = ()> + Describe + 'a {
     one_of(" \t\n\r")
         .expect("a space")
         .repeat(..)
@@ -20,19 +22,19 @@ fn spaces<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = ()> + 'a {
         .expect("spaces")
 }
 
-fn null<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = ()> + 'a {
+fn null<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = ()> + Describe + 'a {
     tag("null").discard().expect("a null")
 }
 
-fn boolean<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = bool> + 'a {
+fn boolean<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = bool> + Describe + 'a {
     choice((tag("true").map(|_| true), tag("false").map(|_| false))).expect("a boolean")
 }
 
-fn object<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = HashMap<String, JsonValue>> + 'a
-{
+fn object<'a, I: Input<Ok = char> + 'a>(
+) -> impl Parser<I, Output = HashMap<String, JsonValue>> + Describe + 'a {
     (
         string().skip((spaces(), token(':'), spaces())),
-        call!(json_value).skip(spaces()),
+        call!(describe json_value).skip(spaces()),
     )
         .sep_by(token(',').skip(spaces()), ..)
         .collect::<HashMap<String, JsonValue>>()
@@ -40,8 +42,9 @@ fn object<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = HashMap<Str
         .expect("a object")
 }
 
-fn array<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = Vec<JsonValue>> + 'a {
-    call!(json_value)
+fn array<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = Vec<JsonValue>> + Describe + 'a
+{
+    call!(describe json_value)
         .skip(spaces())
         .sep_by(token(',').skip(spaces()), ..)
         .collect::<Vec<_>>()
@@ -49,7 +52,7 @@ fn array<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = Vec<JsonValu
         .expect("an array")
 }
 
-fn number<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = f64> + 'a {
+fn number<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = f64> + Describe + 'a {
     (
         token('-').once().opt(),
         choice_iterable((
@@ -72,37 +75,12 @@ fn number<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = f64> + 'a {
         .expect("a number")
 }
 
-fn string<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = String> + 'a {
-    choice((
-        none_of("\\\""),
-        token('\\').prefix(one_of("\"\\/bfnrtu")).then(|c| match c {
-            '\"' => value('\"').left(),
-            '\\' => value('\\').left(),
-            '/' => value('/').left(),
-            'b' => value('\x08').left(),
-            'f' => value('\x0c').left(),
-            'n' => value('\n').left(),
-            'r' => value('\r').left(),
-            't' => value('\t').left(),
-            'u' => one_of("0123456789abcdefABCDEF")
-                .expect("a hex digit")
-                .times(4)
-                .collect::<String>()
-                .try_map(|s| {
-                    char::from_u32(u32::from_str_radix(&s, 16).unwrap())
-                        .ok_or("a valid unicode codepoint")
-                })
-                .right(),
-            _ => unreachable!(),
-        }),
-    ))
-    .repeat(..)
-    .collect::<String>()
-    .between(token('"'), token('"'))
-    .expect("a string")
+fn string<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = String> + Describe + 'a {
+    string_literal().expect("a string")
 }
 
-fn json_value<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = JsonValue> + 'a {
+fn json_value<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = JsonValue> + Describe + 'a
+{
     choice((
         null().map(|_| JsonValue::Null),
         boolean().map(JsonValue::Boolean),
@@ -114,7 +92,7 @@ fn json_value<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = JsonVal
     .expect("a value")
 }
 
-fn json<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = JsonValue> + 'a {
+fn json<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = JsonValue> + Describe + 'a {
     json_value().between(spaces(), spaces())
 }
 
@@ -139,6 +117,8 @@ fn main() {
             .chars(),
         )
         .buffered_rewind();
-        println!("{:#?}", json().parse(&mut stream).await);
+        let mut parser = json();
+        println!("{:#?}", parser.parse(&mut stream).await);
+        print!("{}", parser.describe().to_ebnf("json"));
     });
 }
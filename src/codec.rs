@@ -0,0 +1,196 @@
+//! Decoding frames with parsers, for [`tokio_util::codec`].
+//!
+//! [`ParserCodec`] implements [`Decoder`] by a parser for [`FrameInput`], so [`Framed`] or
+//! [`FramedRead`] can read frames described by somen grammars.
+//!
+//! [`Decoder`]: tokio_util::codec::Decoder
+//! [`Framed`]: tokio_util::codec::Framed
+//! [`FramedRead`]: tokio_util::codec::FramedRead
+use bytes::{Buf, BytesMut};
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use futures_core::Stream;
+use std::io;
+use tokio_util::codec::Decoder;
+
+use crate::error::{ParseError, Status};
+use crate::parser::Parser;
+use crate::stream::peek::Peek;
+use crate::stream::{Positioned, Rewind};
+
+/// The error type for [`ParserCodec`], whose positions are offsets from the beginning of the frame.
+pub type CodecError = ParseError<usize, io::Error>;
+
+/// The input for parsers in [`ParserCodec`], reading bytes of the current frame.
+///
+/// It returns [`Poll::Pending`] when it runs out of the received bytes, until the end of the
+/// underlying stream. Positions are offsets from the beginning of the frame.
+///
+/// It never fails, the error type is [`io::Error`] for [`Decoder`].
+#[derive(Debug, Default)]
+pub struct FrameInput {
+    buf: BytesMut,
+    position: usize,
+    eof: bool,
+}
+
+impl Stream for FrameInput {
+    type Item = Result<u8, io::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.buf.get(self.position) {
+            Some(&byte) => {
+                self.position += 1;
+                Poll::Ready(Some(Ok(byte)))
+            }
+            None if self.eof => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl Positioned for FrameInput {
+    type Locator = usize;
+
+    #[inline]
+    fn position(&self) -> Self::Locator {
+        self.position
+    }
+}
+
+impl Rewind for FrameInput {
+    type Marker = usize;
+
+    #[inline]
+    fn mark(self: Pin<&mut Self>) -> Result<Self::Marker, Self::Error> {
+        Ok(self.position)
+    }
+
+    #[inline]
+    fn rewind(mut self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        self.position = marker;
+        Ok(())
+    }
+}
+
+impl Peek for FrameInput {
+    fn poll_peek(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<Result<&[Self::Ok], Self::Error>> {
+        let this = self.get_mut();
+        let rest = &this.buf[this.position..];
+        if rest.len() < n && !this.eof {
+            Poll::Pending
+        } else {
+            Poll::Ready(Ok(&rest[..n.min(rest.len())]))
+        }
+    }
+}
+
+/// A [`Decoder`] parsing frames with a parser.
+///
+/// The parser is resumed with its state by each call of [`decode`] as bytes arrive, instead of
+/// restarting from the beginning of the frame. Each item is a pair of the output and the number of
+/// bytes the frame consumed.
+///
+/// Parsers must consume at least one byte for each frame, otherwise [`Framed`] would yield frames
+/// forever. After an error, the codec starts a new frame from the beginning of the buffer, which
+/// is left as is.
+///
+/// # Examples
+/// ```
+/// use bytes::BytesMut;
+/// use somen::prelude::*;
+/// use somen::codec::ParserCodec;
+/// use tokio_util::codec::Decoder;
+///
+/// let line = is(|b: &u8| *b != b'\n').repeat(..).collect::<Vec<_>>().skip(token(b'\n'));
+/// let mut codec = ParserCodec::new(line);
+///
+/// let mut buf = BytesMut::from(&b"hel"[..]);
+/// assert_eq!(codec.decode(&mut buf).unwrap(), None);
+///
+/// buf.extend_from_slice(b"lo\nwor");
+/// assert_eq!(codec.decode(&mut buf).unwrap(), Some((b"hello".to_vec(), 6)));
+/// assert_eq!(&buf[..], b"wor");
+///
+/// // An incomplete frame at the end of the stream is an error.
+/// assert!(codec.decode_eof(&mut buf).is_err());
+/// ```
+///
+/// [`decode`]: Decoder::decode
+/// [`Framed`]: tokio_util::codec::Framed
+#[derive(Debug)]
+pub struct ParserCodec<P: Parser<FrameInput>> {
+    parser: P,
+    state: P::State,
+    input: FrameInput,
+}
+
+impl<P: Parser<FrameInput>> ParserCodec<P> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(parser: P) -> Self {
+        Self {
+            parser,
+            state: Default::default(),
+            input: FrameInput::default(),
+        }
+    }
+
+    /// Extracts the parser.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.parser
+    }
+
+    /// Resumes parsing the current frame with the bytes in `src`.
+    fn poll_frame(
+        &mut self,
+        src: &mut BytesMut,
+        eof: bool,
+    ) -> Result<Option<(P::Output, usize)>, CodecError> {
+        self.input.buf = mem::take(src);
+        self.input.eof = eof;
+        let mut cx = Context::from_waker(Waker::noop());
+        let res = self
+            .parser
+            .poll_parse(Pin::new(&mut self.input), &mut cx, &mut self.state);
+        *src = mem::take(&mut self.input.buf);
+
+        let res = match res {
+            Poll::Ready(res) => res,
+            Poll::Pending => return Ok(None),
+        };
+        let len = mem::take(&mut self.input.position);
+        self.state = Default::default();
+        match res {
+            Ok(Status::Success(output, _)) => {
+                src.advance(len);
+                Ok(Some((output, len)))
+            }
+            Ok(Status::Failure(err, _)) => Err(ParseError::Parser(err)),
+            Err(err) => Err(ParseError::Stream(err)),
+        }
+    }
+}
+
+impl<P: Parser<FrameInput>> Decoder for ParserCodec<P> {
+    type Item = (P::Output, usize);
+    type Error = CodecError;
+
+    #[inline]
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        self.poll_frame(src, false)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        if src.is_empty() && self.input.position == 0 {
+            return Ok(None);
+        }
+        self.poll_frame(src, true)
+    }
+}
@@ -0,0 +1,522 @@
+//! Deserializing with [`serde`] by grammars written in somen.
+//!
+//! A self-describing format is described by implementing [`Format`], whose methods parse its
+//! primitives (values, and delimiters of sequences and maps) with somen parsers. Then
+//! [`Deserializer`] drives them as a [`serde::Deserializer`]. See [`json`] for an example.
+//!
+//! Parsers are run synchronously by [`parse_ready`], so the input must be ready without waiting,
+//! e.g. streams made from iterators or slices.
+pub mod json;
+
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::future::Future;
+use core::ops::Range;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use futures_core::TryStream;
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+
+use crate::error::ParseError;
+use crate::parser::{Parser, ParserExt};
+use crate::stream::Positioned;
+
+/// The result type for methods of [`Format`].
+pub type DeResult<T, I> = Result<T, DeError<<I as Positioned>::Locator, <I as TryStream>::Error>>;
+
+/// A self-describing format, which parses primitives of the data model.
+///
+/// Each method parses a primitive with [`parse_ready`], including whitespaces or comments before
+/// it if any.
+pub trait Format<I: Positioned + ?Sized> {
+    /// Determines the kind of the next value, without consuming the value itself.
+    fn peek_kind(&mut self, input: &mut I) -> DeResult<Kind, I>;
+
+    /// Parses a null value.
+    fn parse_null(&mut self, input: &mut I) -> DeResult<(), I>;
+
+    /// Parses a boolean.
+    fn parse_bool(&mut self, input: &mut I) -> DeResult<bool, I>;
+
+    /// Parses a number.
+    fn parse_number(&mut self, input: &mut I) -> DeResult<Number, I>;
+
+    /// Parses a string.
+    fn parse_string(&mut self, input: &mut I) -> DeResult<String, I>;
+
+    /// Parses the beginning of a sequence.
+    fn seq_start(&mut self, input: &mut I) -> DeResult<(), I>;
+
+    /// Parses a separator or the end of a sequence, returns `true` if an element follows.
+    ///
+    /// `first` is `true` before the first element.
+    fn seq_next(&mut self, input: &mut I, first: bool) -> DeResult<bool, I>;
+
+    /// Parses the beginning of a map.
+    fn map_start(&mut self, input: &mut I) -> DeResult<(), I>;
+
+    /// Parses a separator or the end of a map, returns `true` if an entry follows.
+    ///
+    /// `first` is `true` before the first entry.
+    fn map_next(&mut self, input: &mut I, first: bool) -> DeResult<bool, I>;
+
+    /// Parses a separator between a key and a value.
+    fn map_value(&mut self, input: &mut I) -> DeResult<(), I>;
+
+    /// Parses the end of the input, after the top-level value.
+    fn end(&mut self, input: &mut I) -> DeResult<(), I>;
+}
+
+/// Kinds of values, returned by [`Format::peek_kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// A null value.
+    Null,
+    /// A boolean.
+    Bool,
+    /// A number.
+    Number,
+    /// A string.
+    String,
+    /// A sequence.
+    Seq,
+    /// A map.
+    Map,
+}
+
+/// Numbers, returned by [`Format::parse_number`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Number {
+    /// A non-negative integer.
+    PosInt(u64),
+    /// A negative integer.
+    NegInt(i64),
+    /// A floating point number.
+    Float(f64),
+}
+
+/// The error type for deserializers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeError<L, E> {
+    /// An error while parsing or reading the input.
+    Parse(ParseError<L, E>),
+
+    /// The input was not ready.
+    Pending,
+
+    /// An error reported by [`Deserialize`] implementations.
+    ///
+    /// The position is the span of the value being deserialized.
+    ///
+    /// [`Deserialize`]: serde::Deserialize
+    Custom {
+        /// The error message.
+        message: String,
+        /// The position where the error has occured.
+        position: Option<Range<L>>,
+    },
+}
+
+impl<L, E> DeError<L, E> {
+    /// Sets the position of a custom error if it is not known.
+    fn at(self, start: L, end: L) -> Self {
+        match self {
+            Self::Custom {
+                message,
+                position: None,
+            } => Self::Custom {
+                message,
+                position: Some(start..end),
+            },
+            err => err,
+        }
+    }
+}
+
+impl<L, E> From<ParseError<L, E>> for DeError<L, E> {
+    #[inline]
+    fn from(error: ParseError<L, E>) -> Self {
+        Self::Parse(error)
+    }
+}
+
+impl<L: fmt::Debug, E: fmt::Display> fmt::Display for DeError<L, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(ParseError::Parser(err)) => {
+                write!(
+                    f,
+                    "at {:?}..{:?}: {}",
+                    err.position.start, err.position.end, err
+                )
+            }
+            Self::Parse(ParseError::Stream(e)) => e.fmt(f),
+            Self::Pending => f.write_str("the input is not ready."),
+            Self::Custom {
+                message,
+                position: Some(position),
+            } => write!(
+                f,
+                "at {:?}..{:?}: {}",
+                position.start, position.end, message
+            ),
+            Self::Custom {
+                message,
+                position: None,
+            } => f.write_str(message),
+        }
+    }
+}
+
+impl<L: fmt::Debug, E: fmt::Debug + fmt::Display> std::error::Error for DeError<L, E> {}
+
+impl<L: fmt::Debug, E: fmt::Debug + fmt::Display> de::Error for DeError<L, E> {
+    #[inline]
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Custom {
+            message: msg.to_string(),
+            position: None,
+        }
+    }
+}
+
+/// Runs a parser on the input synchronously.
+///
+/// Fails with [`DeError::Pending`] if the input is not ready.
+///
+/// # Examples
+/// ```
+/// use somen::prelude::*;
+/// use somen::de::parse_ready;
+///
+/// let mut stream = stream::from_iter("abc".chars()).positioned::<usize>();
+/// assert_eq!(parse_ready(token('a'), &mut stream), Ok('a'));
+/// assert!(parse_ready(token('a'), &mut stream).is_err());
+/// ```
+pub fn parse_ready<P, I>(mut parser: P, input: &mut I) -> DeResult<P::Output, I>
+where
+    P: Parser<I>,
+    I: Positioned + Unpin + ?Sized,
+{
+    let mut cx = Context::from_waker(Waker::noop());
+    match Pin::new(&mut parser.parse(input)).poll(&mut cx) {
+        Poll::Ready(res) => res.map_err(DeError::Parse),
+        Poll::Pending => Err(DeError::Pending),
+    }
+}
+
+/// Deserializes a value from the input in the format, and checks the end of the input.
+///
+/// # Examples
+/// ```
+/// use somen::prelude::*;
+/// use somen::de::{self, json::Json};
+///
+/// let mut stream = stream::from_iter("[1, 2, 3]".chars()).buffered_rewind();
+/// assert_eq!(de::from_input::<Vec<u8>, _, _>(&mut stream, Json), Ok(vec![1, 2, 3]));
+/// ```
+pub fn from_input<T, I, F>(input: &mut I, format: F) -> DeResult<T, I>
+where
+    T: DeserializeOwned,
+    I: Positioned + Unpin + ?Sized,
+    I::Locator: fmt::Debug,
+    I::Error: fmt::Debug + fmt::Display,
+    F: Format<I>,
+{
+    let mut deserializer = Deserializer::new(input, format);
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/// A [`serde::Deserializer`] parsing the input in a [`Format`].
+///
+/// Implemented for `&mut Deserializer`.
+#[derive(Debug)]
+pub struct Deserializer<'a, I: ?Sized, F> {
+    input: &'a mut I,
+    format: F,
+}
+
+impl<'a, I, F> Deserializer<'a, I, F>
+where
+    I: Positioned + Unpin + ?Sized,
+    F: Format<I>,
+{
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(input: &'a mut I, format: F) -> Self {
+        Self { input, format }
+    }
+
+    /// Checks the end of the input, after deserializing a value.
+    #[inline]
+    pub fn end(&mut self) -> DeResult<(), I> {
+        self.format.end(self.input)
+    }
+
+    /// Extracts the format.
+    #[inline]
+    pub fn into_format(self) -> F {
+        self.format
+    }
+
+    /// Calls `f` visiting the value starting from `start`, and sets the position to the error.
+    fn visit<T>(
+        &mut self,
+        start: I::Locator,
+        f: impl FnOnce(&mut Self) -> DeResult<T, I>,
+    ) -> DeResult<T, I> {
+        f(self).map_err(|err| err.at(start, self.input.position()))
+    }
+}
+
+impl<'de, I, F> de::Deserializer<'de> for &mut Deserializer<'_, I, F>
+where
+    I: Positioned + Unpin + ?Sized,
+    I::Locator: fmt::Debug,
+    I::Error: fmt::Debug + fmt::Display,
+    F: Format<I>,
+{
+    type Error = DeError<I::Locator, I::Error>;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let kind = self.format.peek_kind(self.input)?;
+        let start = self.input.position();
+        self.visit(start, |de| match kind {
+            Kind::Null => {
+                de.format.parse_null(de.input)?;
+                visitor.visit_unit()
+            }
+            Kind::Bool => visitor.visit_bool(de.format.parse_bool(de.input)?),
+            Kind::Number => match de.format.parse_number(de.input)? {
+                Number::PosInt(n) => visitor.visit_u64(n),
+                Number::NegInt(n) => visitor.visit_i64(n),
+                Number::Float(n) => visitor.visit_f64(n),
+            },
+            Kind::String => visitor.visit_string(de.format.parse_string(de.input)?),
+            Kind::Seq => {
+                de.format.seq_start(de.input)?;
+                let mut access = Access::new(de);
+                let value = visitor.visit_seq(&mut access)?;
+                access.finish(Format::seq_next, "trailing elements")?;
+                Ok(value)
+            }
+            Kind::Map => {
+                de.format.map_start(de.input)?;
+                let mut access = Access::new(de);
+                let value = visitor.visit_map(&mut access)?;
+                access.finish(Format::map_next, "trailing entries")?;
+                Ok(value)
+            }
+        })
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.format.peek_kind(self.input)? == Kind::Null {
+            self.format.parse_null(self.input)?;
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    #[inline]
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Unit variants are strings, and other variants are maps with a single entry.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let kind = self.format.peek_kind(self.input)?;
+        let start = self.input.position();
+        self.visit(start, |de| match kind {
+            Kind::Map => {
+                de.format.map_start(de.input)?;
+                let mut access = Access::new(de);
+                if !access.next(Format::map_next)? {
+                    return Err(de::Error::custom("expected a variant"));
+                }
+                let value = visitor.visit_enum(&mut access)?;
+                access.finish(Format::map_next, "trailing entries")?;
+                Ok(value)
+            }
+            _ => visitor.visit_enum(de.format.parse_string(de.input)?.into_deserializer()),
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Accesses elements of sequences, entries of maps, or variants of enums.
+struct Access<'a, 'b, I: ?Sized, F> {
+    de: &'a mut Deserializer<'b, I, F>,
+    first: bool,
+    done: bool,
+}
+
+impl<'a, 'b, I, F> Access<'a, 'b, I, F>
+where
+    I: Positioned + Unpin + ?Sized,
+    F: Format<I>,
+{
+    #[inline]
+    fn new(de: &'a mut Deserializer<'b, I, F>) -> Self {
+        Self {
+            de,
+            first: true,
+            done: false,
+        }
+    }
+
+    /// Parses a separator or the end by `next`, returns `true` if an item follows.
+    fn next(&mut self, next: fn(&mut F, &mut I, bool) -> DeResult<bool, I>) -> DeResult<bool, I> {
+        if self.done {
+            return Ok(false);
+        }
+        let res = next(&mut self.de.format, self.de.input, self.first)?;
+        self.first = false;
+        self.done = !res;
+        Ok(res)
+    }
+
+    /// Checks that no items are left after visiting.
+    fn finish(
+        &mut self,
+        next: fn(&mut F, &mut I, bool) -> DeResult<bool, I>,
+        msg: &'static str,
+    ) -> DeResult<(), I>
+    where
+        I::Locator: fmt::Debug,
+        I::Error: fmt::Debug + fmt::Display,
+    {
+        if self.next(next)? {
+            Err(de::Error::custom(msg))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'de, I, F> de::SeqAccess<'de> for Access<'_, '_, I, F>
+where
+    I: Positioned + Unpin + ?Sized,
+    I::Locator: fmt::Debug,
+    I::Error: fmt::Debug + fmt::Display,
+    F: Format<I>,
+{
+    type Error = DeError<I::Locator, I::Error>;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        if self.next(Format::seq_next)? {
+            seed.deserialize(&mut *self.de).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<'de, I, F> de::MapAccess<'de> for Access<'_, '_, I, F>
+where
+    I: Positioned + Unpin + ?Sized,
+    I::Locator: fmt::Debug,
+    I::Error: fmt::Debug + fmt::Display,
+    F: Format<I>,
+{
+    type Error = DeError<I::Locator, I::Error>;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        if self.next(Format::map_next)? {
+            seed.deserialize(&mut *self.de).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        self.de.format.map_value(self.de.input)?;
+        seed.deserialize(&mut *self.de)
+    }
+}
+
+impl<'de, I, F> de::EnumAccess<'de> for &mut Access<'_, '_, I, F>
+where
+    I: Positioned + Unpin + ?Sized,
+    I::Locator: fmt::Debug,
+    I::Error: fmt::Debug + fmt::Display,
+    F: Format<I>,
+{
+    type Error = DeError<I::Locator, I::Error>;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(&mut *self.de)?;
+        self.de.format.map_value(self.de.input)?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de, I, F> de::VariantAccess<'de> for &mut Access<'_, '_, I, F>
+where
+    I: Positioned + Unpin + ?Sized,
+    I::Locator: fmt::Debug,
+    I::Error: fmt::Debug + fmt::Display,
+    F: Format<I>,
+{
+    type Error = DeError<I::Locator, I::Error>;
+
+    #[inline]
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        de::Deserialize::deserialize(&mut *self.de)
+    }
+
+    #[inline]
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(&mut *self.de)
+    }
+
+    #[inline]
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_seq(&mut *self.de, visitor)
+    }
+
+    #[inline]
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_map(&mut *self.de, visitor)
+    }
+}
@@ -0,0 +1,232 @@
+//! The JSON format.
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt;
+use serde::de::DeserializeOwned;
+
+use super::{parse_ready, DeError, DeResult, Format, Kind, Number};
+use crate::parser::iterable::{choice_iterable, IterableParserExt};
+use crate::parser::text::string_literal;
+use crate::parser::{choice, eof, one_of, tag, token, value, Parser, ParserExt};
+use crate::stream::Input;
+
+/// The JSON format.
+///
+/// # Examples
+/// ```
+/// use serde::Deserialize;
+/// use somen::de::json;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Image {
+///     width: u32,
+///     title: String,
+///     animated: bool,
+///     ids: Vec<u32>,
+///     thumbnail: Option<String>,
+/// }
+///
+/// let image = json::from_str::<Image>(
+///     r#"{
+///         "width": 800,
+///         "title": "View from 15th Floor",
+///         "animated": false,
+///         "ids": [116, 943, 234],
+///         "thumbnail": null
+///     }"#,
+/// );
+/// assert_eq!(
+///     image,
+///     Ok(Image {
+///         width: 800,
+///         title: String::from("View from 15th Floor"),
+///         animated: false,
+///         ids: vec![116, 943, 234],
+///         thumbnail: None,
+///     })
+/// );
+///
+/// // Errors point to the position in the input.
+/// let err = json::from_str::<Vec<u32>>("[1, true]").unwrap_err();
+/// assert_eq!(err.to_string(), "at 4..8: invalid type: boolean `true`, expected u32");
+///
+/// let err = json::from_str::<Vec<u32>>("[1 2]").unwrap_err();
+/// assert_eq!(err.to_string(), "at 3..4: expected ',' or ']'.");
+///
+/// // Raw control characters in strings and numbers out of the range of `f64` are invalid.
+/// let err = json::from_str::<String>("\"a\tb\"").unwrap_err();
+/// assert_eq!(err.to_string(), "at 2..3: expected a non-control character.");
+///
+/// let err = json::from_str::<f64>("1e400").unwrap_err();
+/// assert_eq!(err.to_string(), "at 0..5: expected a valid number.");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Json;
+
+/// Deserializes a value from JSON in the input.
+#[inline]
+pub fn from_input<T, I>(input: &mut I) -> DeResult<T, I>
+where
+    T: DeserializeOwned,
+    I: Input<Ok = char> + Unpin + ?Sized,
+    I::Locator: fmt::Debug,
+    I::Error: fmt::Debug + fmt::Display,
+{
+    super::from_input(input, Json)
+}
+
+/// Deserializes a value from a JSON string.
+///
+/// Positions in errors are counted in characters.
+#[inline]
+pub fn from_str<T: DeserializeOwned>(s: &str) -> Result<T, DeError<usize, Infallible>> {
+    let chars = s.chars().collect::<Vec<_>>();
+    from_input(&mut crate::stream::from_slice(&chars))
+}
+
+fn spaces<I: Input<Ok = char> + ?Sized>() -> impl Parser<I, Output = ()> {
+    one_of(" \t\n\r")
+        .expect("a space")
+        .repeat(..)
+        .discard()
+        .expect("spaces")
+}
+
+fn number<I: Input<Ok = char> + ?Sized>() -> impl Parser<I, Output = String> {
+    (
+        token('-').once().opt(),
+        choice_iterable((
+            token('0').once(),
+            (
+                one_of("123456789").expect("a non-zero digit").once(),
+                one_of("0123456789").expect("a digit").repeat(..),
+            ),
+        )),
+        (token('.').once(), one_of("0123456789").repeat(1..)).opt(),
+        (
+            one_of("eE").expect("e").once(),
+            one_of("+-").once().opt(),
+            one_of("0123456789").repeat(1..),
+        )
+            .opt(),
+    )
+        .collect::<String>()
+        .expect("a number")
+}
+
+/// Parses a separator `sep` or a closing `close`, returns `true` if an item follows.
+fn next<I>(
+    input: &mut I,
+    first: bool,
+    sep: char,
+    close: char,
+    expects: &'static str,
+) -> DeResult<bool, I>
+where
+    I: Input<Ok = char> + Unpin + ?Sized,
+{
+    parse_ready(spaces(), input)?;
+    if first {
+        parse_ready(token(close).map(|_| false).or(value(true)), input)
+    } else {
+        parse_ready(
+            choice((token(sep).map(|_| true), token(close).map(|_| false))).expect(expects),
+            input,
+        )
+    }
+}
+
+impl<I> Format<I> for Json
+where
+    I: Input<Ok = char> + Unpin + ?Sized,
+{
+    fn peek_kind(&mut self, input: &mut I) -> DeResult<Kind, I> {
+        parse_ready(spaces(), input)?;
+        let c = parse_ready(one_of("nft\"[{-0123456789").expect("a value").peek(), input)?;
+        Ok(match c {
+            'n' => Kind::Null,
+            't' | 'f' => Kind::Bool,
+            '"' => Kind::String,
+            '[' => Kind::Seq,
+            '{' => Kind::Map,
+            _ => Kind::Number,
+        })
+    }
+
+    fn parse_null(&mut self, input: &mut I) -> DeResult<(), I> {
+        parse_ready(spaces(), input)?;
+        parse_ready(tag("null").discard().expect("a null"), input)
+    }
+
+    fn parse_bool(&mut self, input: &mut I) -> DeResult<bool, I> {
+        parse_ready(spaces(), input)?;
+        parse_ready(
+            choice((tag("true").map(|_| true), tag("false").map(|_| false))).expect("a boolean"),
+            input,
+        )
+    }
+
+    fn parse_number(&mut self, input: &mut I) -> DeResult<Number, I> {
+        parse_ready(spaces(), input)?;
+        parse_ready(
+            number().try_map(|n| {
+                // Numbers too large for `f64` are rejected instead of being infinite.
+                let float = || match n.parse::<f64>() {
+                    Ok(f) if f.is_finite() => Ok(Number::Float(f)),
+                    _ => Err("a valid number"),
+                };
+                if n.contains(['.', 'e', 'E']) {
+                    float()
+                } else if n.starts_with('-') {
+                    n.parse().map(Number::NegInt).or_else(|_| float())
+                } else {
+                    n.parse().map(Number::PosInt).or_else(|_| float())
+                }
+            }),
+            input,
+        )
+    }
+
+    fn parse_string(&mut self, input: &mut I) -> DeResult<String, I> {
+        parse_ready(spaces(), input)?;
+        parse_ready(
+            string_literal().control_chars(false).expect("a string"),
+            input,
+        )
+    }
+
+    fn seq_start(&mut self, input: &mut I) -> DeResult<(), I> {
+        parse_ready(spaces(), input)?;
+        parse_ready(token('[').discard(), input)
+    }
+
+    fn seq_next(&mut self, input: &mut I, first: bool) -> DeResult<bool, I> {
+        next(input, first, ',', ']', "',' or ']'")
+    }
+
+    fn map_start(&mut self, input: &mut I) -> DeResult<(), I> {
+        parse_ready(spaces(), input)?;
+        parse_ready(token('{').discard(), input)
+    }
+
+    fn map_next(&mut self, input: &mut I, first: bool) -> DeResult<bool, I> {
+        let res = next(input, first, ',', '}', "',' or '}'")?;
+        if res {
+            // Keys must be strings.
+            parse_ready(spaces(), input)?;
+            parse_ready(token('"').expect("a string").peek(), input)?;
+        }
+        Ok(res)
+    }
+
+    fn map_value(&mut self, input: &mut I) -> DeResult<(), I> {
+        parse_ready(spaces(), input)?;
+        parse_ready(token(':').discard(), input)
+    }
+
+    fn end(&mut self, input: &mut I) -> DeResult<(), I> {
+        parse_ready(spaces(), input)?;
+        parse_ready(eof(), input)
+    }
+}
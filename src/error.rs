@@ -1,6 +1,10 @@
 //! Types for error handling.
 
+#[cfg(feature = "miette")]
+mod diagnostic;
 mod expects;
+#[cfg(feature = "ariadne")]
+mod report;
 
 use core::fmt;
 use core::ops::Range;
@@ -0,0 +1,55 @@
+use core::fmt;
+use miette::{Diagnostic, LabeledSpan};
+
+use super::{Error, ParseError};
+use crate::stream::position::Offset;
+
+/// Labels the span of the error with expected tokens.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use miette::Diagnostic;
+/// use somen::prelude::*;
+///
+/// let mut parser = is(|c: &char| c.is_ascii_digit())
+///     .expect("a digit")
+///     .repeat(1..)
+///     .count()
+///     .skip(token(';').expect("';'"));
+/// let mut stream = stream::from_iter("12x".chars()).buffered_rewind();
+/// let err = parser.parse(&mut stream).await.unwrap_err();
+///
+/// let label = err.labels().unwrap().next().unwrap();
+/// assert_eq!((label.offset(), label.len()), (2, 1));
+/// assert_eq!(label.label(), Some("expected one of ';', or a digit"));
+///
+/// // Attach the source to render it by miette.
+/// let report = miette::Report::new(err).with_source_code("12x");
+/// assert_eq!(report.to_string(), "expected one of ';', or a digit.");
+/// # });
+/// ```
+impl<L: Offset + fmt::Debug> Diagnostic for Error<L> {
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let start = self.position.start.offset();
+        let end = self.position.end.offset();
+        Some(Box::new(core::iter::once(LabeledSpan::new_with_span(
+            Some(format!("expected {}", self.expects)),
+            (start, end.saturating_sub(start)),
+        ))))
+    }
+}
+
+/// Forwards to [`Error`], stream errors have no labels.
+impl<L, E> Diagnostic for ParseError<L, E>
+where
+    L: Offset + fmt::Debug + 'static,
+    E: std::error::Error + 'static,
+{
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        match self {
+            Self::Parser(err) => err.labels(),
+            Self::Stream(_) => None,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use ariadne::{Label, Report, ReportKind};
+use core::fmt;
+use core::hash::Hash;
+use core::ops::Range;
+
+use super::{Error, ParseError};
+use crate::stream::position::Offset;
+
+impl<L: Offset> Error<L> {
+    /// Builds a report of [`ariadne`] for the source `id`, labeling the span with expected tokens.
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use ariadne::Source;
+    /// use somen::prelude::*;
+    ///
+    /// let mut parser = is(|c: &char| c.is_ascii_digit())
+    ///     .expect("a digit")
+    ///     .repeat(1..)
+    ///     .count()
+    ///     .skip(token(';').expect("';'"));
+    /// let mut stream = stream::from_iter("12x".chars()).buffered_rewind();
+    /// let err = parser.parse(&mut stream).await.unwrap_err();
+    ///
+    /// let mut buf = Vec::new();
+    /// err.to_report("input.txt")
+    ///     .write(("input.txt", Source::from("12x")), &mut buf)
+    ///     .unwrap();
+    /// let output = String::from_utf8(buf).unwrap();
+    /// assert!(output.contains("input.txt:1:3"));
+    /// assert!(output.contains("expected one of ';', or a digit"));
+    /// # });
+    /// ```
+    ///
+    /// [`ariadne`]: https://docs.rs/ariadne
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "ariadne")))]
+    pub fn to_report<Id>(&self, id: Id) -> Report<'static, (Id, Range<usize>)>
+    where
+        Id: fmt::Debug + Hash + Eq + ToOwned + Clone,
+    {
+        let span = (id, self.position.start.offset()..self.position.end.offset());
+        Report::build(ReportKind::Error, span.clone())
+            .with_message(self)
+            .with_label(Label::new(span).with_message(format!("expected {}", self.expects)))
+            .finish()
+    }
+}
+
+impl<L: Offset, E: fmt::Display> ParseError<L, E> {
+    /// Builds a report of [`ariadne`] for the source `id`.
+    ///
+    /// Stream errors have no positions, so they are reported at the beginning of the source.
+    ///
+    /// [`ariadne`]: https://docs.rs/ariadne
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "ariadne")))]
+    pub fn to_report<Id>(&self, id: Id) -> Report<'static, (Id, Range<usize>)>
+    where
+        Id: fmt::Debug + Hash + Eq + ToOwned + Clone,
+    {
+        match self {
+            Self::Parser(err) => err.to_report(id),
+            Self::Stream(e) => Report::build(ReportKind::Error, (id, 0..0))
+                .with_message(e)
+                .finish(),
+        }
+    }
+}
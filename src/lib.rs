@@ -10,9 +10,18 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "codec")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "codec")))]
+pub mod codec;
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "serde")))]
+pub mod de;
 pub mod error;
 pub mod parser;
 pub mod stream;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod testing;
 
 mod macros;
 
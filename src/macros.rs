@@ -1,6 +1,24 @@
 /// Calls recursive parsers.
+///
+/// With a leading `generate`, the parser keeps the ability to generate inputs (requires the
+/// `generate` feature). See the module `parser::generate` for an example.
+///
+/// With a leading `describe`, the parser keeps the ability to describe its structure (requires
+/// the `alloc` feature). See the module `parser::grammar` for an example.
 #[macro_export]
 macro_rules! call {
+    (generate $func:expr) => {{
+        use $crate::parser::iterable::IterableParserExt;
+        use $crate::parser::ParserExt;
+        $crate::parser::lazy(|| ($func)().no_state().boxed_generate())
+    }};
+    (describe $func:expr) => {{
+        use $crate::parser::iterable::IterableParserExt;
+        use $crate::parser::ParserExt;
+        // Defined outside of `lazy` so that the closure implements `Fn`, as required by `Describe`.
+        let f = || ($func)().no_state().boxed_describe();
+        $crate::parser::lazy(f)
+    }};
     ($func:expr) => {{
         use $crate::parser::iterable::IterableParserExt;
         use $crate::parser::ParserExt;
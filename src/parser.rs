@@ -1,8 +1,18 @@
 //! Basic parsers and combinators.
 
 pub mod atomic;
+pub mod binary;
 pub mod combinator;
+pub mod first;
+#[cfg(feature = "generate")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "generate")))]
+pub mod generate;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod grammar;
 pub mod iterable;
+pub mod slice;
+pub mod text;
 pub mod wrapper;
 
 mod future;
@@ -17,6 +27,16 @@ use core::pin::Pin;
 use core::task::Context;
 
 use crate::error::{Expects, PolledResult};
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateParser};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, DescribeParser};
+use crate::stream::context::Contextual;
+#[cfg(feature = "alloc")]
+use crate::stream::cst::Cst;
+use crate::stream::limit::Nesting;
+use crate::stream::peek;
+use crate::stream::trace::Traced;
 use crate::stream::{Input, Positioned};
 use atomic::*;
 use combinator::*;
@@ -37,6 +57,8 @@ where
 }
 
 /// Produces a parser (or an iterable parser) from the function at the time of parsing.
+///
+/// If `f` implements [`Fn`], the parser produced by it is also used to describe the structure.
 #[inline]
 pub fn lazy<F, P>(f: F) -> Lazy<F>
 where
@@ -169,6 +191,214 @@ where
     assert_parser(IsSome::new(cond))
 }
 
+/// Succeeds if the next token matches the condition, without consuming it.
+///
+/// It works like `is(cond).peek()`, but looks at the buffer of the stream by [`Peek`] instead of
+/// marking and rewinding.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::followed_by;
+///
+/// let mut parser = tag("0").skip(followed_by(|c: &char| *c == 'x'));
+///
+/// let mut stream = stream::from_iter("0x".chars()).buffered_rewind();
+/// assert_eq!(parser.parse(&mut stream).await, Ok("0"));
+/// assert_eq!(any().parse(&mut stream).await, Ok('x'));
+///
+/// let mut stream = stream::from_iter("01".chars()).buffered_rewind();
+/// assert!(parser.parse(&mut stream).await.is_err());
+/// # });
+/// ```
+///
+/// [`Peek`]: crate::stream::peek::Peek
+#[inline]
+pub fn followed_by<I, F>(cond: F) -> FollowedBy<I, F>
+where
+    I: Positioned + peek::Peek + ?Sized,
+    F: FnMut(&I::Ok) -> bool,
+{
+    assert_parser(FollowedBy::new(cond))
+}
+
+/// Succeeds if the next token doesn't match the condition or the input reached the end, without
+/// consuming any tokens.
+///
+/// It works like `is(cond).fail()`, but looks at the buffer of the stream by [`Peek`] instead of
+/// marking and rewinding.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::not_followed_by;
+///
+/// let mut parser = tag("if").skip(not_followed_by(|c: &char| c.is_alphanumeric()));
+///
+/// let mut stream = stream::from_iter("if".chars()).buffered_rewind();
+/// assert_eq!(parser.parse(&mut stream).await, Ok("if"));
+///
+/// let mut stream = stream::from_iter("ifx".chars()).buffered_rewind();
+/// assert!(parser.parse(&mut stream).await.is_err());
+/// # });
+/// ```
+///
+/// [`Peek`]: crate::stream::peek::Peek
+#[inline]
+pub fn not_followed_by<I, F>(cond: F) -> NotFollowedBy<I, F>
+where
+    I: Positioned + peek::Peek + ?Sized,
+    F: FnMut(&I::Ok) -> bool,
+{
+    assert_parser(NotFollowedBy::new(cond))
+}
+
+/// Returns at most `n` upcoming tokens without consuming them.
+///
+/// Fewer tokens are returned only if the input reaches the end.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::lookahead;
+///
+/// let mut stream = stream::from_iter("abc".chars()).buffered_rewind();
+/// assert_eq!(lookahead(2).parse(&mut stream).await, Ok(vec!['a', 'b']));
+/// assert_eq!(lookahead(5).parse(&mut stream).await, Ok(vec!['a', 'b', 'c']));
+/// assert_eq!(any().parse(&mut stream).await, Ok('a'));
+/// assert_eq!(lookahead(usize::MAX).parse(&mut stream).await, Ok(vec!['b', 'c']));
+/// assert_eq!(tag("bc").parse(&mut stream).await, Ok("bc"));
+///
+/// let mut stream = stream::from_iter("abc".chars()).record_to_vec();
+/// assert_eq!(any().parse(&mut stream).await, Ok('a'));
+/// assert_eq!(lookahead(usize::MAX).parse(&mut stream).await, Ok(vec!['b', 'c']));
+/// # });
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[inline]
+pub fn lookahead<I>(n: usize) -> Lookahead<I>
+where
+    I: Positioned + peek::Peek + ?Sized,
+    I::Ok: Clone,
+{
+    assert_parser(Lookahead::new(n))
+}
+
+/// Parses the longest sequence of tokens matching the condition, and collects them.
+///
+/// It may be empty. Tokens are looked by [`Peek`] before consuming. Use
+/// [`slice::take_while`] to borrow from [`Contiguous`] inputs without collecting.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::take_while;
+///
+/// let mut parser = take_while::<String, _, _>(|c: &char| c.is_alphabetic());
+/// let mut stream = stream::from_iter("abc123".chars()).buffered_rewind();
+/// assert_eq!(parser.parse(&mut stream).await, Ok(String::from("abc")));
+/// assert_eq!(parser.parse(&mut stream).await, Ok(String::new()));
+/// # });
+/// ```
+///
+/// [`Peek`]: crate::stream::peek::Peek
+/// [`Contiguous`]: crate::stream::contiguous::Contiguous
+#[inline]
+pub fn take_while<E, I, F>(cond: F) -> TakeWhile<I, F, E>
+where
+    I: Positioned + peek::Peek + ?Sized,
+    F: FnMut(&I::Ok) -> bool,
+    E: Default + Extend<I::Ok>,
+{
+    assert_parser(TakeWhile::new(cond, 0))
+}
+
+/// Parses the longest non-empty sequence of tokens matching the condition, and collects them.
+///
+/// On failure, the error spans the first token. Give it a name by [`expect`].
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::take_while1;
+///
+/// let mut parser = take_while1::<String, _, _>(|c: &char| c.is_ascii_digit()).expect("a digit");
+/// let mut stream = stream::from_iter("42x".chars()).buffered_rewind();
+/// assert_eq!(parser.parse(&mut stream).await, Ok(String::from("42")));
+///
+/// let err = parser.parse(&mut stream).await.unwrap_err();
+/// assert_eq!(err.to_string(), "expected a digit.");
+/// assert!(matches!(err, somen::error::ParseError::Parser(e) if e.position == (2..3)));
+/// # });
+/// ```
+///
+/// [`expect`]: ParserExt::expect
+#[inline]
+pub fn take_while1<E, I, F>(cond: F) -> TakeWhile<I, F, E>
+where
+    I: Positioned + peek::Peek + ?Sized,
+    F: FnMut(&I::Ok) -> bool,
+    E: Default + Extend<I::Ok>,
+{
+    assert_parser(TakeWhile::new(cond, 1))
+}
+
+/// Parses tokens until the pattern appears, and collects the tokens before the pattern.
+///
+/// The pattern is not consumed. Fails if the pattern never appears.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::take_until;
+///
+/// let mut parser = take_until::<String, _, _>(['-', '-']);
+/// let mut stream = stream::from_iter("a-b--c".chars()).buffered_rewind();
+/// assert_eq!(parser.parse(&mut stream).await, Ok(String::from("a-b")));
+/// assert_eq!(tag("--").parse(&mut stream).await, Ok("--"));
+/// assert!(parser.parse(&mut stream).await.is_err());
+/// # });
+/// ```
+#[inline]
+pub fn take_until<E, I, B>(pattern: B) -> TakeUntil<I, B, E>
+where
+    I: Positioned + peek::Peek + ?Sized,
+    I::Ok: PartialEq,
+    B: AsRef<[I::Ok]>,
+    E: Default + Extend<I::Ok>,
+{
+    assert_parser(TakeUntil::new(pattern))
+}
+
+/// Parses `n` tokens, and collects them.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::take;
+///
+/// let mut stream = stream::from_iter("abcde".chars()).positioned::<usize>();
+/// assert_eq!(take::<String, _>(3).parse(&mut stream).await, Ok(String::from("abc")));
+/// assert!(take::<String, _>(3).parse(&mut stream).await.is_err());
+/// # });
+/// ```
+#[inline]
+pub fn take<E, I>(n: usize) -> Take<I, E>
+where
+    I: Positioned + ?Sized,
+    E: Default + Extend<I::Ok>,
+{
+    assert_parser(Take::new(n))
+}
+
 /// Parses a sequence of tokens.
 #[inline]
 pub fn tokens<'a, I, T>(tokens: T) -> Tokens<'a, I, T>
@@ -190,6 +420,121 @@ where
     assert_parser(Tag::new(tag))
 }
 
+/// Parses the longest match of a regular expression, returns the matched text.
+///
+/// The pattern is compiled to a DFA, and supports literals, `.`, character classes (`[a-z]`,
+/// `[^0-9]`, `\d`, `\w`, `\s`, ...), groups, alternations and repetitions (`*`, `+`, `?`,
+/// `{n,m}`). The match always starts at the current position. Tokens read beyond the match are
+/// rewound, so the input must implement [`Rewind`]. Use [`with_position`] to get the span.
+///
+/// # Panics
+/// Panics if the pattern is invalid, use [`Regex::new`] to handle the error.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::regex;
+///
+/// let mut number = regex(r"-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?");
+/// let mut stream = stream::from_iter("-12.5e3,0.x".chars()).buffered_rewind();
+/// assert_eq!(number.parse(&mut stream).await, Ok(String::from("-12.5e3")));
+/// assert_eq!(token(',').parse(&mut stream).await, Ok(','));
+/// // The longest match "0" is taken, ".x" is left.
+/// assert_eq!(number.parse(&mut stream).await, Ok(String::from("0")));
+/// assert_eq!(token('.').parse(&mut stream).await, Ok('.'));
+/// assert!(number.parse(&mut stream).await.is_err());
+///
+/// // Tokens read beyond the match are rewound, even if the automaton got stuck on them.
+/// let mut stream = stream::from_iter("abc".chars()).buffered_rewind();
+/// assert_eq!(regex(r"a|ab[^\s\S]").parse(&mut stream).await, Ok(String::from("a")));
+/// assert_eq!(any().parse(&mut stream).await, Ok('b'));
+///
+/// // Works on bytes too.
+/// let mut ident = regex(r"[A-Za-z_]\w*").with_position();
+/// let mut stream = stream::from_slice(b"foo_1 bar");
+/// assert_eq!(ident.parse(&mut stream).await, Ok((b"foo_1".to_vec(), 0..5)));
+/// # });
+/// ```
+///
+/// [`Rewind`]: crate::stream::Rewind
+/// [`with_position`]: crate::parser::ParserExt::with_position
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[inline]
+pub fn regex<I>(pattern: &'static str) -> Regex<I>
+where
+    I: Input + ?Sized,
+    I::Ok: RegexToken,
+{
+    match Regex::new(pattern) {
+        Ok(regex) => assert_parser(regex),
+        Err(err) => panic!("invalid regular expression {:?}: {}", pattern, err),
+    }
+}
+
+/// Parses a length with `len`, then parses exactly that number of tokens with `p`.
+///
+/// The input is limited to the frame while parsing with `p`, and it fails if `p` leaves some
+/// tokens in the frame. `p` can be a [`Parser`] or an [`IterableParser`], and the input should
+/// implement [`Window`] (see [`windowed`]).
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::{count_prefixed, length_prefixed, window};
+///
+/// // A string prefixed by its length in bytes.
+/// let mut parser = length_prefixed(any(), any().repeat(..).collect::<Vec<_>>());
+/// let mut stream = stream::from_slice(b"\x03abcd").windowed();
+/// assert_eq!(parser.parse(&mut stream).await, Ok(b"abc".to_vec()));
+///
+/// // The frame must be consumed entirely.
+/// let mut parser = window(3, token(b'a'));
+/// let mut stream = stream::from_slice(b"abc").windowed();
+/// assert!(parser.parse(&mut stream).await.is_err());
+///
+/// // The number of items can be prefixed as well.
+/// let mut parser = count_prefixed(any(), any()).collect::<Vec<_>>();
+/// let mut stream = stream::from_slice(b"\x02xyz");
+/// assert_eq!(parser.parse(&mut stream).await, Ok(b"xy".to_vec()));
+/// # });
+/// ```
+///
+/// [`IterableParser`]: iterable::IterableParser
+/// [`Window`]: crate::stream::window::Window
+/// [`windowed`]: crate::stream::StreamBuilder::windowed
+#[inline]
+pub fn length_prefixed<L, P>(len: L, p: P) -> LengthPrefixed<L, P> {
+    // Supports both `Parser` and `IterableParser`.
+    LengthPrefixed::new(len, p)
+}
+
+/// Parses exactly `len` tokens with `p`, like [`length_prefixed`] with a fixed length.
+#[inline]
+pub fn window<I, P>(len: usize, p: P) -> LengthPrefixed<Value<I, usize>, P>
+where
+    I: Positioned + ?Sized,
+{
+    length_prefixed(value(len), p)
+}
+
+/// Parses a count with `count`, then returns a [`IterableParser`] parses with `p` exactly that
+/// number of times.
+///
+/// [`IterableParser`]: iterable::IterableParser
+#[inline]
+pub fn count_prefixed<C, P, I>(count: C, p: P) -> CountPrefixed<C, P>
+where
+    C: Parser<I>,
+    C::Output: TryInto<usize>,
+    P: Parser<I>,
+    I: Positioned + ?Sized,
+{
+    assert_iterable_parser(CountPrefixed::new(count, p))
+}
+
 /// A conventional function to produce a nested [`or`] parser from a tuple of parsers.
 ///
 /// For example, `choice((a, b, c))` is equivalent to `a.or(b).or(c)`.
@@ -204,6 +549,92 @@ where
     assert_parser(choice.into_parser())
 }
 
+/// Parses all the parsers in a tuple in any order, each of them at most once.
+///
+/// Remaining members are tried in order at each step, so members are found regardless of the
+/// order. Members succeeding without consuming any input are optional, and their outputs are used
+/// as defaults if they are never found (use [`opt`] or [`or`] with [`value`] to make optional
+/// members). If some required members are missing, it fails with the expected tokens of them.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::permutation;
+///
+/// let mut parser = permutation((
+///     tag("-a"),
+///     tag("-b").opt(),
+///     tag("-c").map(|_| 1).or(value(0)),
+/// ));
+///
+/// let mut stream = stream::from_iter("-c-a-b".chars()).buffered_rewind();
+/// assert_eq!(parser.parse(&mut stream).await, Ok(("-a", Some("-b"), 1)));
+///
+/// let mut stream = stream::from_iter("-a".chars()).buffered_rewind();
+/// assert_eq!(parser.parse(&mut stream).await, Ok(("-a", None, 0)));
+///
+/// // "-a" is required.
+/// let mut stream = stream::from_iter("-b".chars()).buffered_rewind();
+/// assert!(parser.parse(&mut stream).await.is_err());
+/// # });
+/// ```
+///
+/// [`opt`]: ParserExt::opt
+/// [`or`]: ParserExt::or
+#[inline]
+pub fn permutation<T, I>(parsers: T) -> Permutation<T>
+where
+    Permutation<T>: Parser<I>,
+    I: Input + ?Sized,
+{
+    assert_parser(Permutation::new(parsers))
+}
+
+/// Parses one of the parsers in a tuple, choosing candidates by the first token.
+///
+/// Unlike [`choice`], the first token is peeked once and only the members which can start with
+/// it (see [`First`]) are tried, so mismatching alternatives cost nothing. If several members are
+/// viable, they are tried in order like [`or`]. If none of them are, it fails with the expected
+/// tokens of all members without running any of them.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::dispatch;
+///
+/// let mut parser = dispatch((
+///     tag("let").map(|_| 0),
+///     tag("if").map(|_| 1),
+///     tag("in").map(|_| 2),
+///     is(|c: &char| c.is_ascii_digit()).map(|_| 3),
+/// ));
+///
+/// let mut stream = stream::from_iter("in".chars()).buffered_rewind();
+/// assert_eq!(parser.parse(&mut stream).await, Ok(2));
+///
+/// let mut stream = stream::from_iter("7".chars()).buffered_rewind();
+/// assert_eq!(parser.parse(&mut stream).await, Ok(3));
+///
+/// // Fails without trying any member.
+/// let mut stream = stream::from_iter("x".chars()).buffered_rewind();
+/// let err = parser.parse(&mut stream).await.unwrap_err();
+/// assert_eq!(err.to_string(), "expected one of <cond>, if, in, or let.");
+/// # });
+/// ```
+///
+/// [`First`]: first::First
+/// [`or`]: ParserExt::or
+#[inline]
+pub fn dispatch<T, I>(parsers: T) -> Dispatch<T>
+where
+    Dispatch<T>: Parser<I>,
+    I: Input + ?Sized,
+{
+    assert_parser(Dispatch::new(parsers))
+}
+
 /// A trait for parsers.
 #[cfg_attr(feature = "nightly", doc(notable_trait))]
 pub trait Parser<I: Positioned + ?Sized> {
@@ -254,6 +685,32 @@ pub trait ParserExt<I: Positioned + ?Sized>: Parser<I> {
         assert_parser(Box::new(self))
     }
 
+    /// Wraps the parser into a [`Box`], keeping the ability to generate inputs.
+    #[cfg(feature = "generate")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "generate")))]
+    #[inline]
+    fn boxed_generate<'a>(
+        self,
+    ) -> Box<dyn GenerateParser<I, Output = Self::Output, State = Self::State> + 'a>
+    where
+        Self: Sized + Generate<I::Ok> + 'a,
+    {
+        assert_parser(Box::new(self))
+    }
+
+    /// Wraps the parser into a [`Box`], keeping the ability to describe its structure.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn boxed_describe<'a>(
+        self,
+    ) -> Box<dyn DescribeParser<I, Output = Self::Output, State = Self::State> + 'a>
+    where
+        Self: Sized + Describe + 'a,
+    {
+        assert_parser(Box::new(self))
+    }
+
     /// Merges [`State`] into parser itself.
     ///
     /// [`State`]: Parser::State
@@ -306,6 +763,9 @@ pub trait ParserExt<I: Positioned + ?Sized>: Parser<I> {
     }
 
     /// Returns a parse result without consuming input.
+    ///
+    /// To look at a single token, [`followed_by`] is cheaper for streams implementing
+    /// [`Peek`](crate::stream::peek::Peek).
     #[inline]
     fn peek(self) -> Peek<Self>
     where
@@ -316,6 +776,9 @@ pub trait ParserExt<I: Positioned + ?Sized>: Parser<I> {
     }
 
     /// Succeeds if the parser failed parsing. Never consumes input.
+    ///
+    /// To look at a single token, [`not_followed_by`] is cheaper for streams implementing
+    /// [`Peek`](crate::stream::peek::Peek).
     #[inline]
     fn fail(self) -> Fail<Self>
     where
@@ -345,6 +808,20 @@ pub trait ParserExt<I: Positioned + ?Sized>: Parser<I> {
         assert_parser(Skip::new(self, p))
     }
 
+    /// Parses with `self`, then skips trailing whitespaces.
+    ///
+    /// To skip comments as well, use [`Trivia::lexeme`] instead.
+    ///
+    /// [`Trivia::lexeme`]: crate::parser::text::Trivia::lexeme
+    #[inline]
+    fn lexeme(self) -> Skip<Self, text::Trivia<I>>
+    where
+        Self: Sized,
+        I: Input<Ok = char>,
+    {
+        assert_parser(Skip::new(self, text::trivia()))
+    }
+
     /// Parses with `p` prefixed by `self`.
     #[inline]
     fn prefix<P>(self, p: P) -> Prefix<Self, P>
@@ -563,6 +1040,64 @@ pub trait ParserExt<I: Positioned + ?Sized>: Parser<I> {
         assert_parser(Satisfy::new(self, f))
     }
 
+    /// Tracks the nesting depth while parsing with `self`.
+    ///
+    /// Wrapping recursive parsers with it guards against deeply nested inputs, with a stream
+    /// limiting the depth like [`Limited`].
+    ///
+    /// [`Limited`]: crate::stream::limit::Limited
+    #[inline]
+    fn nested(self) -> Nested<Self>
+    where
+        Self: Sized,
+        I: Nesting,
+    {
+        // Supports both `Parser` and `IterableParser`.
+        Nested::new(self)
+    }
+
+    /// Converts an output value into another type, with the context of the input.
+    ///
+    /// Modifications on the context will be undone if the input is rewound.
+    #[inline]
+    fn map_ctx<F, O>(self, f: F) -> MapCtx<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Output, &mut I::Ctx) -> O,
+        I: Contextual,
+    {
+        assert_parser(MapCtx::new(self, f))
+    }
+
+    /// Parses with `self`, passes output and the context of the input to the function `f` and
+    /// parses with a returned [`Parser`].
+    #[inline]
+    fn then_ctx<F, Q>(self, f: F) -> ThenCtx<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Output, &mut I::Ctx) -> Q,
+        Q: Parser<I>,
+        I: Contextual,
+    {
+        assert_parser(ThenCtx::new(self, f))
+    }
+
+    /// Checks an output value with the function, which can refer to the context of the input.
+    ///
+    /// Unlike [`satisfy`], the failure is not exclusive, so that other alternatives can be tried
+    /// (e.g. a type name or an identifier).
+    ///
+    /// [`satisfy`]: Self::satisfy
+    #[inline]
+    fn satisfy_ctx<F>(self, f: F) -> SatisfyCtx<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Output, &mut I::Ctx) -> bool,
+        I: Contextual,
+    {
+        assert_parser(SatisfyCtx::new(self, f))
+    }
+
     /// Modifies expected values.
     #[inline]
     fn map_err<F, E>(self, f: F) -> MapErr<Self, F>
@@ -603,6 +1138,133 @@ pub trait ParserExt<I: Positioned + ?Sized>: Parser<I> {
         assert_parser(Exclusive::new(self, expected.into()))
     }
 
+    /// Wraps the tokens consumed by `self` into a node of `kind`, in a concrete syntax tree built
+    /// by [`CstStream`].
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use somen::prelude::*;
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// enum Kind { Root, Hex, Dec, Digits }
+    ///
+    /// let digits = || {
+    ///     is(|c: &char| c.is_ascii_hexdigit())
+    ///         .repeat(1..)
+    ///         .discard()
+    ///         .node(Kind::Digits)
+    /// };
+    /// let num = || tag("0x").prefix(digits()).node(Kind::Hex).or(digits().node(Kind::Dec));
+    /// let mut parser = num().skip(token(' ')).and(num());
+    ///
+    /// let mut stream = stream::from_iter("0x1f 012".chars()).buffered_rewind().cst();
+    /// parser.parse(&mut stream).await.unwrap();
+    ///
+    /// // `tag("0x")` has consumed "01" of the second number, but the abandoned `Hex` node is not
+    /// // recorded.
+    /// let tree = stream.into_tree(Kind::Root);
+    /// let nodes = tree.nodes().map(|n| (n.kind.clone(), n.span.clone())).collect::<Vec<_>>();
+    /// assert_eq!(nodes, [(Kind::Hex, 0..4), (Kind::Dec, 5..8)]);
+    /// assert_eq!(tree.nodes().next().unwrap().nodes().next().unwrap().span, 2..4);
+    ///
+    /// // The tree preserves all the tokens.
+    /// assert_eq!(tree.text::<String>(), "0x1f 012");
+    /// # });
+    /// ```
+    ///
+    /// [`CstStream`]: crate::stream::cst::CstStream
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn node<K>(self, kind: K) -> Node<Self, K>
+    where
+        Self: Sized,
+        K: Clone,
+        I: Cst<Kind = K>,
+    {
+        assert_parser(Node::new(self, kind))
+    }
+
+    /// Reports entering, success and failure of the parser to the input, labeled with `name`.
+    ///
+    /// The input is usually a stream wrapped by [`traced`], see [`PrettyPrinter`] for
+    /// examples.
+    ///
+    /// [`traced`]: crate::stream::StreamBuilder::traced
+    /// [`PrettyPrinter`]: crate::stream::trace::PrettyPrinter
+    #[inline]
+    fn trace(self, name: &'static str) -> Trace<Self>
+    where
+        Self: Sized,
+        I: Traced,
+    {
+        assert_parser(Trace::new(self, name))
+    }
+
+    /// Marks all the errors as "exclusive", so enclosing parsers like [`or`] and [`repeat`] report
+    /// them as is instead of rewinding the input. The original errors are kept, unlike
+    /// [`exclusive`].
+    ///
+    /// [`or`]: Self::or
+    /// [`repeat`]: Self::repeat
+    /// [`exclusive`]: Self::exclusive
+    #[inline]
+    fn cut(self) -> Cut<Self>
+    where
+        Self: Sized,
+    {
+        assert_parser(Cut::new(self))
+    }
+
+    /// Parses with `self`, and then with `p` like [`and`], but commits to this branch once `self`
+    /// succeeds: the errors of `p` are marked as [`cut`], so that enclosing [`or`]s and
+    /// [`repeat`]s never backtrack over `self`.
+    ///
+    /// It only changes how errors are reported. The markers of enclosing parsers are still held
+    /// until they complete, so tokens buffered by [`BufferedRewinder`] are not released at the
+    /// commit point.
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use somen::prelude::*;
+    ///
+    /// // `spanned` moves the error to the start, so `or` backtracks and tries the other branch.
+    /// let mut parser = tag("let ")
+    ///     .and(token('x'))
+    ///     .discard()
+    ///     .spanned()
+    ///     .or(tag("let y").discard());
+    /// let mut stream = stream::from_iter("let y".chars()).buffered_rewind();
+    /// assert_eq!(parser.parse(&mut stream).await, Ok(()));
+    ///
+    /// // After committing, the error is reported as is.
+    /// let mut parser = tag("let ")
+    ///     .commit(token('x'))
+    ///     .discard()
+    ///     .spanned()
+    ///     .or(tag("let y").discard());
+    /// let mut stream = stream::from_iter("let y".chars()).buffered_rewind();
+    /// let err = parser.parse(&mut stream).await.unwrap_err();
+    /// assert!(matches!(err, somen::error::ParseError::Parser(e) if e.position == (4..5)));
+    /// # });
+    /// ```
+    ///
+    /// [`and`]: Self::and
+    /// [`cut`]: Self::cut
+    /// [`or`]: Self::or
+    /// [`repeat`]: Self::repeat
+    /// [`BufferedRewinder`]: crate::stream::rewind::BufferedRewinder
+    #[inline]
+    fn commit<P>(self, p: P) -> (Self, Cut<P>)
+    where
+        Self: Sized,
+        P: Parser<I>,
+    {
+        assert_parser((self, Cut::new(p)))
+    }
+
     /// Modifies "exclusive" errors as rewindable.
     #[inline]
     fn rewindable(self) -> Rewindable<Self>
@@ -615,7 +1277,7 @@ pub trait ParserExt<I: Positioned + ?Sized>: Parser<I> {
 
 impl<P: Parser<I>, I: Positioned + ?Sized> ParserExt<I> for P {}
 
-impl<'a, P: Parser<I> + ?Sized, I: Positioned + ?Sized> Parser<I> for &'a mut P {
+impl<P: Parser<I> + ?Sized, I: Positioned + ?Sized> Parser<I> for &mut P {
     type Output = P::Output;
     type State = P::State;
 
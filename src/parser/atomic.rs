@@ -3,9 +3,13 @@ mod any;
 mod cond;
 mod eof;
 mod func;
+mod lookahead;
 mod position;
+#[cfg(feature = "alloc")]
+mod regex;
 mod set;
 mod tag;
+mod take;
 mod token;
 mod tokens;
 mod value;
@@ -14,9 +18,17 @@ pub use any::Any;
 pub use cond::{Is, IsNot, IsSome};
 pub use eof::Eof;
 pub use func::Function;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub use lookahead::Lookahead;
+pub use lookahead::{FollowedBy, NotFollowedBy};
 pub use position::Position;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub use regex::{Regex, RegexError, RegexState, RegexToken};
 pub use set::{NoneOf, OneOf, Set};
 pub use tag::Tag;
+pub use take::{Take, TakeState, TakeUntil, TakeWhile};
 pub use token::{Not, Token};
 pub use tokens::Tokens;
 pub use value::{Value, ValueFn};
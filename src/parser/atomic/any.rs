@@ -4,6 +4,9 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::Parser;
 use crate::stream::Positioned;
 
@@ -51,3 +54,31 @@ impl<I: Positioned + ?Sized> Parser<I> for Any<I> {
         }))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized> Describe for Any<I> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Any
+    }
+}
+
+impl<I> First<I::Ok> for Any<I>
+where
+    I: Positioned + ?Sized,
+{
+    #[inline]
+    fn first(&mut self, _token: &I::Ok) -> bool {
+        true
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from("a token"))
+    }
+}
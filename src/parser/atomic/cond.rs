@@ -4,6 +4,9 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::Parser;
 use crate::stream::Positioned;
 
@@ -160,3 +163,69 @@ where
         }))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, F> Describe for Is<I, F> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Opaque
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, F> Describe for IsNot<I, F> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Opaque
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, F> Describe for IsSome<I, F> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Opaque
+    }
+}
+
+impl<I, F> First<I::Ok> for Is<I, F>
+where
+    I: Positioned + ?Sized,
+    F: FnMut(&I::Ok) -> bool,
+{
+    #[inline]
+    fn first(&mut self, token: &I::Ok) -> bool {
+        (self.cond)(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from("<cond>"))
+    }
+}
+
+impl<I, F> First<I::Ok> for IsNot<I, F>
+where
+    I: Positioned + ?Sized,
+    F: FnMut(&I::Ok) -> bool,
+{
+    #[inline]
+    fn first(&mut self, token: &I::Ok) -> bool {
+        !(self.cond)(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from("<not cond>"))
+    }
+}
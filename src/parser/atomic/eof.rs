@@ -4,6 +4,11 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::Parser;
 use crate::stream::Positioned;
 
@@ -51,3 +56,39 @@ impl<I: Positioned + ?Sized> Parser<I> for Eof<I> {
         }))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized> Describe for Eof<I> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::End
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<I: ?Sized, T> Generate<T> for Eof<I> {
+    #[inline]
+    fn generate(&mut self, _gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        Ok(())
+    }
+}
+
+impl<I> First<I::Ok> for Eof<I>
+where
+    I: Positioned + ?Sized,
+{
+    #[inline]
+    fn first(&mut self, _token: &I::Ok) -> bool {
+        false
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from("eof"))
+    }
+}
@@ -3,6 +3,8 @@ use core::pin::Pin;
 use core::task::Context;
 
 use crate::error::PolledResult;
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::Parser;
 use crate::stream::Positioned;
@@ -66,3 +68,11 @@ where
         (self.f)(input, cx, state)
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<F, I: ?Sized, C> Describe for Function<F, I, C> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Opaque
+    }
+}
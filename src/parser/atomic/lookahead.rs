@@ -0,0 +1,238 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::Parser;
+use crate::stream::peek::Peek;
+use crate::stream::Positioned;
+
+/// A parser for function [`lookahead`].
+///
+/// [`lookahead`]: crate::parser::lookahead
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lookahead<I: ?Sized> {
+    n: usize,
+    _phantom: PhantomData<I>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized> Lookahead<I> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Parser<I> for Lookahead<I>
+where
+    I: Positioned + Peek + ?Sized,
+    I::Ok: Clone,
+{
+    type Output = Vec<I::Ok>;
+    type State = ();
+
+    fn poll_parse(
+        &mut self,
+        input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        _state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        let tokens = ready!(input.poll_peek(cx, self.n)?);
+        Poll::Ready(Ok(Status::Success(tokens.to_vec(), None)))
+    }
+}
+
+/// A parser for function [`followed_by`].
+///
+/// [`followed_by`]: crate::parser::followed_by
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FollowedBy<I: ?Sized, F> {
+    cond: F,
+    _phantom: PhantomData<I>,
+}
+
+impl<I: ?Sized, F> FollowedBy<I, F> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(cond: F) -> Self {
+        Self {
+            cond,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, F> Parser<I> for FollowedBy<I, F>
+where
+    I: Positioned + Peek + ?Sized,
+    F: FnMut(&I::Ok) -> bool,
+{
+    type Output = ();
+    type State = ();
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        _state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        let tokens = ready!(input.as_mut().poll_peek(cx, 1)?);
+        Poll::Ready(Ok(match tokens.first() {
+            Some(token) if (self.cond)(token) => Status::Success((), None),
+            _ => Status::Failure(
+                Error {
+                    expects: Expects::from("<cond>"),
+                    position: input.position()..input.position(),
+                },
+                false,
+            ),
+        }))
+    }
+}
+
+/// A parser for function [`not_followed_by`].
+///
+/// [`not_followed_by`]: crate::parser::not_followed_by
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotFollowedBy<I: ?Sized, F> {
+    cond: F,
+    _phantom: PhantomData<I>,
+}
+
+impl<I: ?Sized, F> NotFollowedBy<I, F> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(cond: F) -> Self {
+        Self {
+            cond,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, F> Parser<I> for NotFollowedBy<I, F>
+where
+    I: Positioned + Peek + ?Sized,
+    F: FnMut(&I::Ok) -> bool,
+{
+    type Output = ();
+    type State = ();
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        _state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        let tokens = ready!(input.as_mut().poll_peek(cx, 1)?);
+        Poll::Ready(Ok(match tokens.first() {
+            Some(token) if (self.cond)(token) => Status::Failure(
+                Error {
+                    expects: Expects::from("<not cond>"),
+                    position: input.position()..input.position(),
+                },
+                false,
+            ),
+            _ => Status::Success((), None),
+        }))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized> Describe for Lookahead<I> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Lookahead(Box::new(Grammar::repeat(Grammar::Any, ..=self.n)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, F> Describe for FollowedBy<I, F> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Lookahead(Box::new(Grammar::Opaque))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, F> Describe for NotFollowedBy<I, F> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::NegativeLookahead(Box::new(Grammar::Opaque))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> First<I::Ok> for Lookahead<I>
+where
+    I: Positioned + ?Sized,
+{
+    #[inline]
+    fn first(&mut self, _token: &I::Ok) -> bool {
+        false
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        None
+    }
+}
+
+impl<I, F> First<I::Ok> for FollowedBy<I, F>
+where
+    I: Positioned + ?Sized,
+{
+    #[inline]
+    fn first(&mut self, _token: &I::Ok) -> bool {
+        false
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        None
+    }
+}
+
+impl<I, F> First<I::Ok> for NotFollowedBy<I, F>
+where
+    I: Positioned + ?Sized,
+{
+    #[inline]
+    fn first(&mut self, _token: &I::Ok) -> bool {
+        false
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        None
+    }
+}
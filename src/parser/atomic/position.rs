@@ -2,7 +2,12 @@ use core::marker::PhantomData;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use crate::error::{PolledResult, Status};
+use crate::error::{Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::Parser;
 use crate::stream::Positioned;
 
@@ -45,3 +50,39 @@ impl<I: Positioned + ?Sized> Parser<I> for Position<I> {
         Poll::Ready(Ok(Status::Success(input.position(), None)))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized> Describe for Position<I> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Empty
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<I: ?Sized, T> Generate<T> for Position<I> {
+    #[inline]
+    fn generate(&mut self, _gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        Ok(())
+    }
+}
+
+impl<I> First<I::Ok> for Position<I>
+where
+    I: Positioned + ?Sized,
+{
+    #[inline]
+    fn first(&mut self, _token: &I::Ok) -> bool {
+        false
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        None
+    }
+}
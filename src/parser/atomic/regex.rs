@@ -0,0 +1,200 @@
+mod dfa;
+mod syntax;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::Parser;
+use crate::stream::Input;
+use dfa::Dfa;
+
+/// Tokens which can be matched by [`Regex`].
+///
+/// Bytes are matched as the characters of the same code points, so non-ASCII bytes should be
+/// written by escapes like `\xff` in patterns.
+pub trait RegexToken: Sized {
+    /// The type of matched texts.
+    type Text: Default + Extend<Self>;
+
+    /// Returns the code point of the token.
+    fn code(&self) -> u32;
+}
+
+impl RegexToken for char {
+    type Text = String;
+
+    #[inline]
+    fn code(&self) -> u32 {
+        u32::from(*self)
+    }
+}
+
+impl RegexToken for u8 {
+    type Text = Vec<u8>;
+
+    #[inline]
+    fn code(&self) -> u32 {
+        u32::from(*self)
+    }
+}
+
+/// An error type for invalid patterns of [`Regex`], positions are byte offsets in the pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegexError {
+    UnexpectedEnd,
+    UnexpectedChar(usize),
+    InvalidClass(usize),
+    InvalidEscape(usize),
+    InvalidRepetition(usize),
+    TooLarge,
+}
+
+impl fmt::Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of the pattern"),
+            Self::UnexpectedChar(pos) => write!(f, "unexpected character at {}", pos),
+            Self::InvalidClass(pos) => write!(f, "invalid character class at {}", pos),
+            Self::InvalidEscape(pos) => write!(f, "invalid escape sequence at {}", pos),
+            Self::InvalidRepetition(pos) => write!(f, "invalid repetition at {}", pos),
+            Self::TooLarge => write!(f, "the compiled automaton is too large"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+impl std::error::Error for RegexError {}
+
+/// A parser for function [`regex`].
+///
+/// [`regex`]: crate::parser::regex
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Regex<I: ?Sized> {
+    pattern: &'static str,
+    dfa: Dfa,
+    _phantom: PhantomData<I>,
+}
+
+impl<I: ?Sized> Regex<I> {
+    /// Compiles a pattern into a new instance.
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use somen::prelude::*;
+    /// use somen::parser::atomic::{Regex, RegexError};
+    /// use somen::stream::SliceStream;
+    ///
+    /// let mut stream = stream::from_slice(b"aaa");
+    /// let mut parser = Regex::new("a{2}").unwrap();
+    /// assert_eq!(parser.parse(&mut stream).await, Ok(b"aa".to_vec()));
+    ///
+    /// // Large repetitions are rejected, while repeating empty patterns compiles instantly.
+    /// let err = Regex::<SliceStream<'_, u8>>::new("a{1,100000}").err();
+    /// assert_eq!(err, Some(RegexError::TooLarge));
+    /// let mut parser = Regex::new("((){4000000000}){4000000000}a").unwrap();
+    /// assert_eq!(parser.parse(&mut stream).await, Ok(b"a".to_vec()));
+    /// # });
+    /// ```
+    pub fn new(pattern: &'static str) -> Result<Self, RegexError> {
+        Ok(Self {
+            pattern,
+            dfa: Dfa::new(&syntax::parse(pattern)?)?,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+crate::parser_state! {
+    pub struct RegexState<I: Input; T: Default> {
+        initialized: bool,
+        current: usize,
+        text: T,
+        pending: Vec<I::Ok>,
+        marker: Option<I::Marker>,
+        #[opt(set = set_start)]
+        start: I::Locator,
+    }
+}
+
+impl<I> Parser<I> for Regex<I>
+where
+    I: Input + ?Sized,
+    I::Ok: RegexToken,
+{
+    type Output = <I::Ok as RegexToken>::Text;
+    type State = RegexState<I, <I::Ok as RegexToken>::Text>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        if !state.initialized {
+            state.initialized = true;
+            state.set_start(|| input.position());
+            if self.dfa.is_accept(0) {
+                state.marker = Some(input.as_mut().mark()?);
+            }
+        }
+
+        // Consumes tokens until the automaton stops, remembering the last accepted position.
+        let overread = loop {
+            if self.dfa.is_dead(state.current) {
+                // Tokens after the last accepted position have to be rewound.
+                break !state.pending.is_empty();
+            }
+            let token = match ready!(input.as_mut().try_poll_next(cx)?) {
+                Some(token) => token,
+                None => break true,
+            };
+            state.current = match self.dfa.next(state.current, token.code()) {
+                Some(next) => next,
+                None => break true,
+            };
+            state.pending.push(token);
+            if self.dfa.is_accept(state.current) {
+                state.text.extend(state.pending.drain(..));
+                if let Some(marker) = state.marker.take() {
+                    input.as_mut().drop_marker(marker)?;
+                }
+                state.marker = Some(input.as_mut().mark()?);
+            }
+        };
+
+        Poll::Ready(Ok(match state.marker.take() {
+            Some(marker) => {
+                if overread {
+                    input.as_mut().rewind(marker)?;
+                } else {
+                    input.as_mut().drop_marker(marker)?;
+                }
+                Status::Success(state.text(), None)
+            }
+            None => Status::Failure(
+                Error {
+                    expects: Expects::from(self.pattern),
+                    position: state.start()..input.position(),
+                },
+                false,
+            ),
+        }))
+    }
+}
+
+impl<I: ?Sized> Describe for Regex<I> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Special(format!("/{}/", self.pattern).into())
+    }
+}
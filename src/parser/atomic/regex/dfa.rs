@@ -0,0 +1,196 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::syntax::{Hir, MAX};
+use super::RegexError;
+
+/// The maximum number of states of NFAs and DFAs.
+const STATE_LIMIT: usize = 10_000;
+
+enum NfaState {
+    Split(Vec<usize>),
+    Class(Vec<(u32, u32)>, usize),
+    Match,
+}
+
+/// A Thompson NFA, built from the end to the start so that no patching is needed.
+struct Nfa {
+    states: Vec<NfaState>,
+}
+
+impl Nfa {
+    fn push(&mut self, state: NfaState) -> Result<usize, RegexError> {
+        if self.states.len() >= STATE_LIMIT {
+            return Err(RegexError::TooLarge);
+        }
+        self.states.push(state);
+        Ok(self.states.len() - 1)
+    }
+
+    /// Compiles `hir` followed by the state `next`, returns the entry state.
+    fn compile(&mut self, hir: &Hir, next: usize) -> Result<usize, RegexError> {
+        match hir {
+            Hir::Empty => Ok(next),
+            Hir::Class(ranges) => self.push(NfaState::Class(ranges.clone(), next)),
+            Hir::Concat(items) => items
+                .iter()
+                .rev()
+                .try_fold(next, |next, item| self.compile(item, next)),
+            Hir::Alt(alts) => {
+                let entries = alts
+                    .iter()
+                    .map(|alt| self.compile(alt, next))
+                    .collect::<Result<_, _>>()?;
+                self.push(NfaState::Split(entries))
+            }
+            Hir::Repeat { inner, min, max } => {
+                // If a copy of `inner` adds no states, it only matches the empty string, and so
+                // does the repetition. Returns early, since the loops below never hit the limit.
+                let len = self.states.len();
+                self.compile(inner, next)?;
+                if self.states.len() == len {
+                    return Ok(next);
+                }
+                self.states.truncate(len);
+
+                let mut cur = next;
+                match max {
+                    Some(max) => {
+                        for _ in *min..*max {
+                            let entry = self.compile(inner, cur)?;
+                            cur = self.push(NfaState::Split(vec![entry, cur]))?;
+                        }
+                    }
+                    None => {
+                        let split = self.push(NfaState::Split(Vec::new()))?;
+                        let entry = self.compile(inner, split)?;
+                        self.states[split] = NfaState::Split(vec![entry, cur]);
+                        cur = split;
+                    }
+                }
+                for _ in 0..*min {
+                    cur = self.compile(inner, cur)?;
+                }
+                Ok(cur)
+            }
+        }
+    }
+
+    /// Collects states reachable without consuming tokens.
+    fn closure(&self, states: impl IntoIterator<Item = usize>) -> Vec<usize> {
+        let mut visited = vec![false; self.states.len()];
+        let mut stack: Vec<usize> = states.into_iter().collect();
+        let mut res = Vec::new();
+        while let Some(s) = stack.pop() {
+            if core::mem::replace(&mut visited[s], true) {
+                continue;
+            }
+            match &self.states[s] {
+                NfaState::Split(nexts) => stack.extend(nexts),
+                _ => res.push(s),
+            }
+        }
+        res.sort_unstable();
+        res
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct DfaState {
+    /// Sorted and disjoint ranges of code points, and the next states.
+    trans: Vec<(u32, u32, usize)>,
+    accept: bool,
+}
+
+/// A deterministic finite automaton, whose start state is `0`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dfa {
+    states: Vec<DfaState>,
+}
+
+impl Dfa {
+    /// Compiles [`Hir`] by the subset construction.
+    pub fn new(hir: &Hir) -> Result<Self, RegexError> {
+        let mut nfa = Nfa { states: Vec::new() };
+        let matched = nfa.push(NfaState::Match)?;
+        let start = nfa.compile(hir, matched)?;
+
+        // Splits code points into the intervals where all the NFA states behave the same.
+        let mut bounds = vec![0, MAX + 1];
+        for state in &nfa.states {
+            if let NfaState::Class(ranges, _) = state {
+                for &(lo, hi) in ranges {
+                    bounds.push(lo);
+                    bounds.push(hi + 1);
+                }
+            }
+        }
+        bounds.sort_unstable();
+        bounds.dedup();
+
+        let mut ids = BTreeMap::new();
+        let mut sets = vec![nfa.closure([start])];
+        ids.insert(sets[0].clone(), 0);
+        let mut states = Vec::new();
+        while let Some(set) = sets.get(states.len()).cloned() {
+            let accept = set.contains(&matched);
+            let mut trans: Vec<(u32, u32, usize)> = Vec::new();
+            for w in bounds.windows(2) {
+                let (lo, hi) = (w[0], w[1] - 1);
+                let targets = set.iter().filter_map(|&s| match &nfa.states[s] {
+                    NfaState::Class(ranges, next)
+                        if ranges.iter().any(|&(l, h)| l <= lo && hi <= h) =>
+                    {
+                        Some(*next)
+                    }
+                    _ => None,
+                });
+                let target = nfa.closure(targets);
+                if target.is_empty() {
+                    continue;
+                }
+                let id = match ids.get(&target) {
+                    Some(&id) => id,
+                    None => {
+                        if sets.len() >= STATE_LIMIT {
+                            return Err(RegexError::TooLarge);
+                        }
+                        ids.insert(target.clone(), sets.len());
+                        sets.push(target);
+                        sets.len() - 1
+                    }
+                };
+                match trans.last_mut() {
+                    Some(last) if last.1 + 1 == lo && last.2 == id => last.1 = hi,
+                    _ => trans.push((lo, hi, id)),
+                }
+            }
+            states.push(DfaState { trans, accept });
+        }
+
+        Ok(Self { states })
+    }
+
+    /// Returns `true` if the state accepts.
+    #[inline]
+    pub fn is_accept(&self, state: usize) -> bool {
+        self.states[state].accept
+    }
+
+    /// Returns `true` if no more tokens can be consumed from the state.
+    #[inline]
+    pub fn is_dead(&self, state: usize) -> bool {
+        self.states[state].trans.is_empty()
+    }
+
+    /// Returns the next state, or [`None`] if no transition exists.
+    pub fn next(&self, state: usize, code: u32) -> Option<usize> {
+        let trans = &self.states[state].trans;
+        let idx = trans.partition_point(|&(_, hi, _)| hi < code);
+        match trans.get(idx) {
+            Some(&(lo, _, next)) if lo <= code => Some(next),
+            _ => None,
+        }
+    }
+}
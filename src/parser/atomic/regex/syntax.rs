@@ -0,0 +1,299 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::iter::Peekable;
+use core::str::CharIndices;
+
+use super::RegexError;
+
+/// The maximum code point.
+pub const MAX: u32 = char::MAX as u32;
+
+/// A parsed regular expression.
+#[derive(Clone, Debug)]
+pub enum Hir {
+    Empty,
+    /// Sorted and disjoint ranges of code points.
+    Class(Vec<(u32, u32)>),
+    Concat(Vec<Hir>),
+    Alt(Vec<Hir>),
+    Repeat {
+        inner: Box<Hir>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+/// Parses a pattern into [`Hir`].
+pub fn parse(pattern: &str) -> Result<Hir, RegexError> {
+    let mut parser = SyntaxParser {
+        iter: pattern.char_indices().peekable(),
+        len: pattern.len(),
+    };
+    let hir = parser.alt()?;
+    match parser.iter.next() {
+        Some((pos, _)) => Err(RegexError::UnexpectedChar(pos)),
+        None => Ok(hir),
+    }
+}
+
+struct SyntaxParser<'a> {
+    iter: Peekable<CharIndices<'a>>,
+    len: usize,
+}
+
+impl SyntaxParser<'_> {
+    fn pos(&mut self) -> usize {
+        self.iter.peek().map_or(self.len, |(pos, _)| *pos)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.iter.peek().map(|(_, c)| *c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let res = self.peek() == Some(c);
+        if res {
+            self.iter.next();
+        }
+        res
+    }
+
+    fn next(&mut self) -> Result<char, RegexError> {
+        self.iter
+            .next()
+            .map(|(_, c)| c)
+            .ok_or(RegexError::UnexpectedEnd)
+    }
+
+    fn alt(&mut self) -> Result<Hir, RegexError> {
+        let mut alts = Vec::new();
+        loop {
+            alts.push(self.concat()?);
+            if !self.eat('|') {
+                break;
+            }
+        }
+        Ok(if alts.len() == 1 {
+            alts.pop().unwrap()
+        } else {
+            Hir::Alt(alts)
+        })
+    }
+
+    fn concat(&mut self) -> Result<Hir, RegexError> {
+        let mut items = Vec::new();
+        while !matches!(self.peek(), None | Some('|') | Some(')')) {
+            items.push(self.repeat()?);
+        }
+        Ok(match items.len() {
+            0 => Hir::Empty,
+            1 => items.pop().unwrap(),
+            _ => Hir::Concat(items),
+        })
+    }
+
+    fn repeat(&mut self) -> Result<Hir, RegexError> {
+        let mut hir = self.atom()?;
+        loop {
+            let pos = self.pos();
+            let (min, max) = match self.peek() {
+                Some('*') => (0, None),
+                Some('+') => (1, None),
+                Some('?') => (0, Some(1)),
+                Some('{') => {
+                    self.iter.next();
+                    let min = self.number()?;
+                    let max = if self.eat(',') {
+                        match self.peek() {
+                            Some('}') => None,
+                            _ => Some(self.number()?),
+                        }
+                    } else {
+                        Some(min)
+                    };
+                    if self.peek() != Some('}') || max.is_some_and(|max| max < min) {
+                        return Err(RegexError::InvalidRepetition(pos));
+                    }
+                    (min, max)
+                }
+                _ => return Ok(hir),
+            };
+            self.iter.next();
+            // Lazy quantifiers make no difference on the longest match.
+            self.eat('?');
+            hir = Hir::Repeat {
+                inner: Box::new(hir),
+                min,
+                max,
+            };
+        }
+    }
+
+    fn number(&mut self) -> Result<u32, RegexError> {
+        let pos = self.pos();
+        let mut num: Option<u32> = None;
+        while let Some(d) = self.peek().and_then(|c| c.to_digit(10)) {
+            self.iter.next();
+            num = Some(
+                num.unwrap_or(0)
+                    .checked_mul(10)
+                    .and_then(|n| n.checked_add(d))
+                    .ok_or(RegexError::InvalidRepetition(pos))?,
+            );
+        }
+        num.ok_or(RegexError::InvalidRepetition(pos))
+    }
+
+    fn atom(&mut self) -> Result<Hir, RegexError> {
+        let pos = self.pos();
+        match self.next()? {
+            '(' => {
+                if self.eat('?') && !self.eat(':') {
+                    return Err(RegexError::UnexpectedChar(pos + 1));
+                }
+                let hir = self.alt()?;
+                if !self.eat(')') {
+                    return Err(RegexError::UnexpectedEnd);
+                }
+                Ok(hir)
+            }
+            '[' => self.class(),
+            '.' => Ok(Hir::Class(negate(&[(u32::from('\n'), u32::from('\n'))]))),
+            '\\' => Ok(Hir::Class(self.escape()?)),
+            '*' | '+' | '?' | '{' | ')' | '|' => Err(RegexError::UnexpectedChar(pos)),
+            c => Ok(Hir::Class(Vec::from([(u32::from(c), u32::from(c))]))),
+        }
+    }
+
+    fn class(&mut self) -> Result<Hir, RegexError> {
+        let negated = self.eat('^');
+        let mut ranges = Vec::new();
+        let mut first = true;
+        loop {
+            let pos = self.pos();
+            let lo = match self.next()? {
+                ']' if !first => break,
+                '\\' => {
+                    let class = self.escape()?;
+                    if !matches!(class[..], [(lo, hi)] if lo == hi) {
+                        ranges.extend(class);
+                        first = false;
+                        continue;
+                    }
+                    class[0].0
+                }
+                c => u32::from(c),
+            };
+            first = false;
+
+            let mut iter = self.iter.clone();
+            if matches!(iter.next(), Some((_, '-')))
+                && !matches!(iter.next(), None | Some((_, ']')))
+            {
+                self.iter.next();
+                let hi = match self.next()? {
+                    '\\' => match self.escape()?[..] {
+                        [(lo, hi)] if lo == hi => lo,
+                        _ => return Err(RegexError::InvalidClass(pos)),
+                    },
+                    c => u32::from(c),
+                };
+                if hi < lo {
+                    return Err(RegexError::InvalidClass(pos));
+                }
+                ranges.push((lo, hi));
+            } else {
+                ranges.push((lo, lo));
+            }
+        }
+
+        let ranges = normalize(ranges);
+        Ok(Hir::Class(if negated { negate(&ranges) } else { ranges }))
+    }
+
+    fn escape(&mut self) -> Result<Vec<(u32, u32)>, RegexError> {
+        let pos = self.pos();
+        let single = |c: u32| Ok(Vec::from([(c, c)]));
+        match self.next()? {
+            'd' => Ok(digit()),
+            'w' => Ok(word()),
+            's' => Ok(space()),
+            'D' => Ok(negate(&digit())),
+            'W' => Ok(negate(&word())),
+            'S' => Ok(negate(&space())),
+            'n' => single(0x0a),
+            't' => single(0x09),
+            'r' => single(0x0d),
+            'f' => single(0x0c),
+            'v' => single(0x0b),
+            '0' => single(0x00),
+            'x' => {
+                let hi = self.next()?.to_digit(16);
+                let lo = self.next()?.to_digit(16);
+                match (hi, lo) {
+                    (Some(hi), Some(lo)) => single(hi * 16 + lo),
+                    _ => Err(RegexError::InvalidEscape(pos)),
+                }
+            }
+            'u' => {
+                if !self.eat('{') {
+                    return Err(RegexError::InvalidEscape(pos));
+                }
+                let mut code = 0u32;
+                let mut digits = 0;
+                while let Some(d) = self.peek().and_then(|c| c.to_digit(16)) {
+                    self.iter.next();
+                    code = code.saturating_mul(16).saturating_add(d);
+                    digits += 1;
+                }
+                if !self.eat('}') || digits == 0 || char::from_u32(code).is_none() {
+                    return Err(RegexError::InvalidEscape(pos));
+                }
+                single(code)
+            }
+            c if c.is_ascii_punctuation() || c == ' ' => single(u32::from(c)),
+            _ => Err(RegexError::InvalidEscape(pos)),
+        }
+    }
+}
+
+fn digit() -> Vec<(u32, u32)> {
+    Vec::from([(0x30, 0x39)])
+}
+
+fn word() -> Vec<(u32, u32)> {
+    Vec::from([(0x30, 0x39), (0x41, 0x5a), (0x5f, 0x5f), (0x61, 0x7a)])
+}
+
+fn space() -> Vec<(u32, u32)> {
+    Vec::from([(0x09, 0x0d), (0x20, 0x20)])
+}
+
+/// Sorts ranges and merges overlapping ones.
+fn normalize(mut ranges: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    ranges.sort_unstable();
+    let mut res: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+    for (lo, hi) in ranges {
+        match res.last_mut() {
+            Some(last) if lo <= last.1.saturating_add(1) => last.1 = last.1.max(hi),
+            _ => res.push((lo, hi)),
+        }
+    }
+    res
+}
+
+/// Returns the complement of normalized ranges.
+fn negate(ranges: &[(u32, u32)]) -> Vec<(u32, u32)> {
+    let mut res = Vec::new();
+    let mut next = 0;
+    for &(lo, hi) in ranges {
+        if next < lo {
+            res.push((next, lo - 1));
+        }
+        next = hi + 1;
+    }
+    if next <= MAX {
+        res.push((next, MAX));
+    }
+    res
+}
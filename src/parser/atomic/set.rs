@@ -11,8 +11,15 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator, Sample};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::Parser;
 use crate::stream::Positioned;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 
 /// A parser for function [`one_of`].
 ///
@@ -80,6 +87,25 @@ impl<I: ?Sized, S> NoneOf<I, S> {
             _phantom: PhantomData,
         }
     }
+
+    #[cfg(feature = "alloc")]
+    fn expects(&self) -> Expects
+    where
+        I: Positioned,
+        S: Set<I::Ok>,
+    {
+        Expects::from_iter(
+            self.set
+                .to_expects()
+                .into_iter()
+                .map(|exp| alloc::borrow::Cow::from(format!("not {}", exp))),
+        )
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn expects(&self) -> Expects {
+        Expects::from("<none of set>")
+    }
 }
 
 impl<I, S> Parser<I> for NoneOf<I, S>
@@ -101,15 +127,7 @@ where
             Some(i) if !self.set.contains(&i) => Status::Success(i, None),
             _ => Status::Failure(
                 Error {
-                    #[cfg(feature = "alloc")]
-                    expects: Expects::from_iter(
-                        self.set
-                            .to_expects()
-                            .into_iter()
-                            .map(|exp| alloc::borrow::Cow::from(format!("not {}", exp))),
-                    ),
-                    #[cfg(not(feature = "alloc"))]
-                    expects: Expects::from("<none of set>"),
+                    expects: self.expects(),
                     position: start..input.position(),
                 },
                 false,
@@ -131,9 +149,58 @@ pub trait Set<T> {
     fn to_expects(&self) -> Expects {
         Expects::from("<set>")
     }
+
+    /// Returns the number of leading tokens in `tokens` which the set contains.
+    ///
+    /// It's used by parsers scanning slices at once, and can be overridden for faster scanning.
+    /// Arrays, slices and strings of up to three elements compare tokens without iterating the
+    /// set.
+    ///
+    /// # Examples
+    /// ```
+    /// use somen::parser::atomic::Set;
+    ///
+    /// assert_eq!(Set::<u8>::prefix_len(b" \t", b"  \tx "), 3);
+    /// assert_eq!(Set::<char>::prefix_len("ab", &['b', 'a', 'c']), 2);
+    /// assert_eq!(Set::<char>::prefix_len("abcd", &['d', 'a', 'e']), 2);
+    /// ```
+    #[inline]
+    fn prefix_len(&self, tokens: &[T]) -> usize {
+        default_prefix_len(self, tokens)
+    }
 }
 
-impl<'a, T, S: Set<T> + ?Sized> Set<T> for &'a S {
+// The maximum size of sets scanned by `small_prefix_len`.
+const SMALL_SET: usize = 3;
+
+// Scans tokens with the elements of a small set held in locals, so that the comparisons can be
+// unrolled and vectorized. Returns `None` if the set is not small.
+#[inline]
+fn small_prefix_len<T, U, F>(set: &[U], tokens: &[T], eq: F) -> Option<usize>
+where
+    F: Fn(&U, &T) -> bool,
+{
+    let pos = match set {
+        [] => Some(0),
+        [a] => tokens.iter().position(|t| !eq(a, t)),
+        [a, b] => tokens.iter().position(|t| !eq(a, t) && !eq(b, t)),
+        [a, b, c] => tokens
+            .iter()
+            .position(|t| !eq(a, t) && !eq(b, t) && !eq(c, t)),
+        _ => return None,
+    };
+    Some(pos.unwrap_or(tokens.len()))
+}
+
+#[inline]
+fn default_prefix_len<T, S: Set<T> + ?Sized>(set: &S, tokens: &[T]) -> usize {
+    tokens
+        .iter()
+        .position(|token| !set.contains(token))
+        .unwrap_or(tokens.len())
+}
+
+impl<T, S: Set<T> + ?Sized> Set<T> for &S {
     #[inline]
     fn contains(&self, token: &T) -> bool {
         (**self).contains(token)
@@ -143,6 +210,11 @@ impl<'a, T, S: Set<T> + ?Sized> Set<T> for &'a S {
     fn to_expects(&self) -> Expects {
         (**self).to_expects()
     }
+
+    #[inline]
+    fn prefix_len(&self, tokens: &[T]) -> usize {
+        (**self).prefix_len(tokens)
+    }
 }
 
 impl<
@@ -156,6 +228,12 @@ impl<
         self.iter().any(|i| i == token)
     }
 
+    #[inline]
+    fn prefix_len(&self, tokens: &[T]) -> usize {
+        small_prefix_len(self, tokens, |i, token| i == token)
+            .unwrap_or_else(|| default_prefix_len(self, tokens))
+    }
+
     #[inline]
     #[cfg(feature = "alloc")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
@@ -176,6 +254,12 @@ impl<
         self.iter().any(|i| i == token)
     }
 
+    #[inline]
+    fn prefix_len(&self, tokens: &[T]) -> usize {
+        small_prefix_len(self, tokens, |i, token| i == token)
+            .unwrap_or_else(|| default_prefix_len(self, tokens))
+    }
+
     #[inline]
     #[cfg(feature = "alloc")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
@@ -190,6 +274,22 @@ impl<T: PartialEq<char>> Set<T> for str {
         self.chars().any(|c| *token == c)
     }
 
+    #[inline]
+    fn prefix_len(&self, tokens: &[T]) -> usize {
+        // Short sets of characters are copied to an array of `char`s for comparisons.
+        let mut chars = ['\0'; SMALL_SET];
+        let mut len = 0;
+        for c in self.chars() {
+            if len == SMALL_SET {
+                return default_prefix_len(self, tokens);
+            }
+            chars[len] = c;
+            len += 1;
+        }
+        small_prefix_len(&chars[..len], tokens, |c, token| *token == *c)
+            .unwrap_or_else(|| default_prefix_len(self, tokens))
+    }
+
     #[inline]
     #[cfg(feature = "alloc")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
@@ -203,6 +303,11 @@ impl<T> Set<T> for RangeFull {
     fn contains(&self, _: &T) -> bool {
         true
     }
+
+    #[inline]
+    fn prefix_len(&self, tokens: &[T]) -> usize {
+        tokens.len()
+    }
 }
 
 impl<T: PartialOrd<U>, U: PartialOrd<T>> Set<T> for (Bound<U>, Bound<U>) {
@@ -237,6 +342,11 @@ impl<T, U: PartialEq<T> + Display> Set<T> for alloc::vec::Vec<U> {
         self.iter().any(|i| i == token)
     }
 
+    #[inline]
+    fn prefix_len(&self, tokens: &[T]) -> usize {
+        self.as_slice().prefix_len(tokens)
+    }
+
     #[inline]
     fn to_expects(&self) -> Expects {
         Expects::from_iter(self.iter().map(|t| t.to_string()))
@@ -293,6 +403,11 @@ impl<T: PartialEq<char>> Set<T> for alloc::string::String {
         self.chars().any(|c| *token == c)
     }
 
+    #[inline]
+    fn prefix_len(&self, tokens: &[T]) -> usize {
+        self.as_str().prefix_len(tokens)
+    }
+
     #[inline]
     fn to_expects(&self) -> Expects {
         Expects::from_iter(self.chars().map(|t| t.to_string()))
@@ -312,3 +427,85 @@ impl<T, U: PartialEq<T> + Eq + core::hash::Hash + Display> Set<T> for std::colle
         Expects::from_iter(self.iter().map(|t| t.to_string()))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<I, S> Describe for OneOf<I, S>
+where
+    I: Positioned + ?Sized,
+    S: Set<I::Ok>,
+{
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::from_expects(self.set.to_expects())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, S> Describe for NoneOf<I, S>
+where
+    I: Positioned + ?Sized,
+    S: Set<I::Ok>,
+{
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::sequence([
+            Grammar::NegativeLookahead(Box::new(Grammar::from_expects(self.set.to_expects()))),
+            Grammar::Any,
+        ])
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<I: ?Sized, S: Sample<T>, T> Generate<T> for OneOf<I, S> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        let token = self
+            .set
+            .sample(gen.rng())
+            .ok_or(GenerateError::NoCandidates)?;
+        gen.push(token);
+        Ok(())
+    }
+}
+
+impl<I, S> First<I::Ok> for OneOf<I, S>
+where
+    I: Positioned + ?Sized,
+    S: Set<I::Ok>,
+{
+    #[inline]
+    fn first(&mut self, token: &I::Ok) -> bool {
+        self.set.contains(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(self.set.to_expects())
+    }
+}
+
+impl<I, S> First<I::Ok> for NoneOf<I, S>
+where
+    I: Positioned + ?Sized,
+    S: Set<I::Ok>,
+{
+    #[inline]
+    fn first(&mut self, token: &I::Ok) -> bool {
+        !self.set.contains(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(self.expects())
+    }
+}
@@ -1,3 +1,4 @@
+use core::iter::Peekable;
 use core::marker::PhantomData;
 use core::pin::Pin;
 use core::str::Chars;
@@ -5,8 +6,15 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::Parser;
 use crate::stream::Positioned;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
 
 /// A parser for function [`tag`].
 ///
@@ -31,7 +39,7 @@ impl<I: ?Sized> Tag<I> {
 crate::parser_state! {
     pub struct TagState<I> {
         #[opt]
-        iter: Chars<'static>,
+        iter: Peekable<Chars<'static>>,
         #[opt(set = set_start)]
         start: I::Locator,
         #[opt]
@@ -54,15 +62,19 @@ where
         state: &mut Self::State,
     ) -> PolledResult<Self::Output, I> {
         state.set_start(|| input.position());
-        let iter = state.iter.get_or_insert_with(|| self.tag.chars());
+        let iter = state
+            .iter
+            .get_or_insert_with(|| self.tag.chars().peekable());
         Poll::Ready(Ok(loop {
-            let val = match iter.next() {
-                Some(i) => i,
+            // Keeps the expected character until the input is ready.
+            let val = match iter.peek() {
+                Some(&i) => i,
                 None => break Status::Success(self.tag, None),
             };
 
             let parsed = ready!(input.as_mut().try_poll_next(cx)?);
             state.next.get_or_insert_with(|| input.position());
+            iter.next();
 
             match parsed {
                 Some(i) if i == val => continue,
@@ -79,3 +91,41 @@ where
         }))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized> Describe for Tag<I> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Literal(self.tag.to_string())
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<I: ?Sized, T: From<char>> Generate<T> for Tag<I> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        gen.extend(self.tag.chars().map(T::from));
+        Ok(())
+    }
+}
+
+impl<I> First<I::Ok> for Tag<I>
+where
+    I: Positioned + ?Sized,
+    I::Ok: PartialEq<char>,
+{
+    #[inline]
+    fn first(&mut self, token: &I::Ok) -> bool {
+        matches!(self.tag.chars().next(), Some(c) if *token == c)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.tag.is_empty()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from(self.tag))
+    }
+}
@@ -0,0 +1,301 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::Parser;
+use crate::stream::peek::Peek;
+use crate::stream::Positioned;
+
+crate::parser_state! {
+    pub struct TakeState<I; E: Default> {
+        #[opt(set = set_start)]
+        start: I::Locator,
+        output: E,
+        count: usize,
+    }
+}
+
+/// A parser for functions [`take_while`] and [`take_while1`].
+///
+/// [`take_while`]: crate::parser::take_while
+/// [`take_while1`]: crate::parser::take_while1
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TakeWhile<I: ?Sized, F, E> {
+    cond: F,
+    min: usize,
+    _phantom: PhantomData<I>,
+    _output: PhantomData<E>,
+}
+
+impl<I: ?Sized, F, E> TakeWhile<I, F, E> {
+    /// Creates a new instance, which requires at least `min` tokens.
+    #[inline]
+    pub fn new(cond: F, min: usize) -> Self {
+        Self {
+            cond,
+            min,
+            _phantom: PhantomData,
+            _output: PhantomData,
+        }
+    }
+}
+
+impl<I, F, E> Parser<I> for TakeWhile<I, F, E>
+where
+    I: Positioned + Peek + ?Sized,
+    F: FnMut(&I::Ok) -> bool,
+    E: Default + Extend<I::Ok>,
+{
+    type Output = E;
+    type State = TakeState<I, E>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        state.set_start(|| input.position());
+        loop {
+            let tokens = ready!(input.as_mut().poll_peek(cx, 1)?);
+            if !matches!(tokens.first(), Some(token) if (self.cond)(token)) {
+                break;
+            }
+            if let Some(token) = ready!(input.as_mut().try_poll_next(cx)?) {
+                state.output.extend(Some(token));
+                state.count += 1;
+            }
+        }
+
+        if state.count < self.min {
+            // Consumes the mismatched token, so that the error spans it like `is`.
+            ready!(input.as_mut().try_poll_next(cx)?);
+            return Poll::Ready(Ok(Status::Failure(
+                Error {
+                    expects: Expects::from("<cond>"),
+                    position: state.start()..input.position(),
+                },
+                false,
+            )));
+        }
+        Poll::Ready(Ok(Status::Success(state.output(), None)))
+    }
+}
+
+/// A parser for function [`take_until`].
+///
+/// [`take_until`]: crate::parser::take_until
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TakeUntil<I: ?Sized, B, E> {
+    pattern: B,
+    _phantom: PhantomData<I>,
+    _output: PhantomData<E>,
+}
+
+impl<I: ?Sized, B, E> TakeUntil<I, B, E> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(pattern: B) -> Self {
+        Self {
+            pattern,
+            _phantom: PhantomData,
+            _output: PhantomData,
+        }
+    }
+}
+
+impl<I, B, E> Parser<I> for TakeUntil<I, B, E>
+where
+    I: Positioned + Peek + ?Sized,
+    I::Ok: PartialEq,
+    B: AsRef<[I::Ok]>,
+    E: Default + Extend<I::Ok>,
+{
+    type Output = E;
+    type State = TakeState<I, E>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        state.set_start(|| input.position());
+        let pattern = self.pattern.as_ref();
+        loop {
+            let tokens = ready!(input.as_mut().poll_peek(cx, pattern.len().max(1))?);
+            if tokens.starts_with(pattern) {
+                break;
+            }
+            if tokens.is_empty() {
+                return Poll::Ready(Ok(Status::Failure(
+                    Error {
+                        expects: Expects::from("<tokens>"),
+                        position: state.start()..input.position(),
+                    },
+                    false,
+                )));
+            }
+            if let Some(token) = ready!(input.as_mut().try_poll_next(cx)?) {
+                state.output.extend(Some(token));
+            }
+        }
+        Poll::Ready(Ok(Status::Success(state.output(), None)))
+    }
+}
+
+/// A parser for function [`take`].
+///
+/// [`take`]: crate::parser::take
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Take<I: ?Sized, E> {
+    n: usize,
+    _phantom: PhantomData<I>,
+    _output: PhantomData<E>,
+}
+
+impl<I: ?Sized, E> Take<I, E> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            _phantom: PhantomData,
+            _output: PhantomData,
+        }
+    }
+}
+
+impl<I, E> Parser<I> for Take<I, E>
+where
+    I: Positioned + ?Sized,
+    E: Default + Extend<I::Ok>,
+{
+    type Output = E;
+    type State = TakeState<I, E>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        state.set_start(|| input.position());
+        while state.count < self.n {
+            match ready!(input.as_mut().try_poll_next(cx)?) {
+                Some(token) => {
+                    state.output.extend(Some(token));
+                    state.count += 1;
+                }
+                None => {
+                    return Poll::Ready(Ok(Status::Failure(
+                        Error {
+                            expects: Expects::from("a token"),
+                            position: state.start()..input.position(),
+                        },
+                        false,
+                    )));
+                }
+            }
+        }
+        Poll::Ready(Ok(Status::Success(state.output(), None)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, F, E> Describe for TakeWhile<I, F, E> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::repeat(Grammar::Opaque, self.min..)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, B, E> Describe for TakeUntil<I, B, E> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::repeat(
+            Grammar::sequence([
+                Grammar::NegativeLookahead(Box::new(Grammar::Opaque)),
+                Grammar::Any,
+            ]),
+            ..,
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, E> Describe for Take<I, E> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::repeat(Grammar::Any, self.n..=self.n)
+    }
+}
+
+impl<I, F, E> First<I::Ok> for TakeWhile<I, F, E>
+where
+    I: Positioned + ?Sized,
+    F: FnMut(&I::Ok) -> bool,
+{
+    #[inline]
+    fn first(&mut self, token: &I::Ok) -> bool {
+        (self.cond)(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.min == 0
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from("<cond>"))
+    }
+}
+
+impl<I, B, E> First<I::Ok> for TakeUntil<I, B, E>
+where
+    I: Positioned + ?Sized,
+{
+    #[inline]
+    fn first(&mut self, _token: &I::Ok) -> bool {
+        true
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from("a token"))
+    }
+}
+
+impl<I, E> First<I::Ok> for Take<I, E>
+where
+    I: Positioned + ?Sized,
+{
+    #[inline]
+    fn first(&mut self, _token: &I::Ok) -> bool {
+        true
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.n == 0
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from("a token"))
+    }
+}
@@ -8,8 +8,15 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::Parser;
 use crate::stream::Positioned;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 
 /// A parser for function [`token`].
 ///
@@ -29,6 +36,19 @@ impl<I: ?Sized, T> Token<I, T> {
             _phantom: PhantomData,
         }
     }
+
+    #[cfg(feature = "alloc")]
+    fn expects(&self) -> Expects
+    where
+        T: Display,
+    {
+        Expects::from(self.token.to_string())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn expects(&self) -> Expects {
+        Expects::from("<token>")
+    }
 }
 
 impl<I, #[cfg(feature = "alloc")] T: Display, #[cfg(not(feature = "alloc"))] T> Parser<I>
@@ -51,10 +71,7 @@ where
             Some(i) if self.token == i => Status::Success(i, None),
             _ => Status::Failure(
                 Error {
-                    #[cfg(feature = "alloc")]
-                    expects: Expects::from(self.token.to_string()),
-                    #[cfg(not(feature = "alloc"))]
-                    expects: Expects::from("<token>"),
+                    expects: self.expects(),
                     position: start..input.position(),
                 },
                 false,
@@ -81,6 +98,19 @@ impl<I: ?Sized, T> Not<I, T> {
             _phantom: PhantomData,
         }
     }
+
+    #[cfg(feature = "alloc")]
+    fn expects(&self) -> Expects
+    where
+        T: Display,
+    {
+        Expects::from(format!("not {}", self.token))
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn expects(&self) -> Expects {
+        Expects::from("<not token>")
+    }
 }
 
 impl<I, #[cfg(feature = "alloc")] T: Display, #[cfg(not(feature = "alloc"))] T> Parser<I>
@@ -103,10 +133,7 @@ where
             Some(i) if self.token != i => Status::Success(i, None),
             _ => Status::Failure(
                 Error {
-                    #[cfg(feature = "alloc")]
-                    expects: Expects::from(format!("not {}", self.token)),
-                    #[cfg(not(feature = "alloc"))]
-                    expects: Expects::from("<not token>"),
+                    expects: self.expects(),
                     position: start..input.position(),
                 },
                 false,
@@ -114,3 +141,75 @@ where
         }))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, T: Display> Describe for Token<I, T> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Literal(self.token.to_string())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, T: Display> Describe for Not<I, T> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::sequence([
+            Grammar::NegativeLookahead(Box::new(Grammar::Literal(self.token.to_string()))),
+            Grammar::Any,
+        ])
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<I: ?Sized, T: Clone> Generate<T> for Token<I, T> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        gen.push(self.token.clone());
+        Ok(())
+    }
+}
+
+impl<I, #[cfg(feature = "alloc")] T: Display, #[cfg(not(feature = "alloc"))] T> First<I::Ok>
+    for Token<I, T>
+where
+    I: Positioned + ?Sized,
+    T: PartialEq<I::Ok>,
+{
+    #[inline]
+    fn first(&mut self, token: &I::Ok) -> bool {
+        self.token == *token
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(self.expects())
+    }
+}
+
+impl<I, #[cfg(feature = "alloc")] T: Display, #[cfg(not(feature = "alloc"))] T> First<I::Ok>
+    for Not<I, T>
+where
+    I: Positioned + ?Sized,
+    T: PartialEq<I::Ok>,
+{
+    #[inline]
+    fn first(&mut self, token: &I::Ok) -> bool {
+        self.token != *token
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(self.expects())
+    }
+}
@@ -1,11 +1,21 @@
+use core::iter::Peekable;
 use core::marker::PhantomData;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::Parser;
 use crate::stream::Positioned;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
+#[cfg(feature = "alloc")]
+use core::fmt::Display;
 
 /// A parser for function [`tokens`].
 ///
@@ -45,7 +55,7 @@ where
     T: PartialEq<I::Ok> + 'a,
 {
     type Output = B;
-    type State = TokensState<I, B::IntoIter>;
+    type State = TokensState<I, Peekable<B::IntoIter>>;
 
     fn poll_parse(
         &mut self,
@@ -56,15 +66,17 @@ where
         state.set_start(|| input.position());
         let iter = state
             .iter
-            .get_or_insert_with(|| self.tokens.clone().into_iter());
+            .get_or_insert_with(|| self.tokens.clone().into_iter().peekable());
         Poll::Ready(Ok(loop {
-            let val = match iter.next() {
-                Some(i) => i,
+            // Keeps the expected token until the input is ready.
+            let val = match iter.peek() {
+                Some(&i) => i,
                 None => break Status::Success(self.tokens.clone(), None),
             };
 
             let parsed = ready!(input.as_mut().try_poll_next(cx)?);
             state.next.get_or_insert_with(|| input.position());
+            iter.next();
 
             match parsed {
                 Some(i) if *val == i => continue,
@@ -81,3 +93,57 @@ where
         }))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<'a, I, B, T> Describe for Tokens<'a, I, B>
+where
+    I: ?Sized,
+    B: IntoIterator<Item = &'a T> + Clone,
+    T: Display + 'a,
+{
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::sequence(
+            self.tokens
+                .clone()
+                .into_iter()
+                .map(|t| Grammar::Literal(t.to_string())),
+        )
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<'a, I, B, U, T> Generate<T> for Tokens<'a, I, B>
+where
+    I: ?Sized,
+    B: IntoIterator<Item = &'a U> + Clone,
+    U: Clone + Into<T> + 'a,
+{
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        gen.extend(self.tokens.clone().into_iter().map(|t| t.clone().into()));
+        Ok(())
+    }
+}
+
+impl<'a, I, B, T> First<I::Ok> for Tokens<'a, I, B>
+where
+    I: Positioned + ?Sized,
+    B: IntoIterator<Item = &'a T> + Clone,
+    T: PartialEq<I::Ok> + 'a,
+{
+    #[inline]
+    fn first(&mut self, token: &I::Ok) -> bool {
+        matches!(self.tokens.clone().into_iter().next(), Some(t) if *t == *token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.tokens.clone().into_iter().next().is_none()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from("<tokens>"))
+    }
+}
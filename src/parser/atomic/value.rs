@@ -2,7 +2,12 @@ use core::marker::PhantomData;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use crate::error::{PolledResult, Status};
+use crate::error::{Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::Parser;
 use crate::stream::Positioned;
 
@@ -75,3 +80,75 @@ impl<I: Positioned + ?Sized, F: FnMut() -> T, T> Parser<I> for ValueFn<I, F> {
         Poll::Ready(Ok(Status::Success((self.f)(), None)))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, T> Describe for Value<I, T> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Empty
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, F> Describe for ValueFn<I, F> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Empty
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<I: ?Sized, X, T> Generate<T> for Value<I, X> {
+    #[inline]
+    fn generate(&mut self, _gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<I: ?Sized, F, T> Generate<T> for ValueFn<I, F> {
+    #[inline]
+    fn generate(&mut self, _gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        Ok(())
+    }
+}
+
+impl<I, T> First<I::Ok> for Value<I, T>
+where
+    I: Positioned + ?Sized,
+{
+    #[inline]
+    fn first(&mut self, _token: &I::Ok) -> bool {
+        false
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        None
+    }
+}
+
+impl<I, F> First<I::Ok> for ValueFn<I, F>
+where
+    I: Positioned + ?Sized,
+{
+    #[inline]
+    fn first(&mut self, _token: &I::Ok) -> bool {
+        false
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        None
+    }
+}
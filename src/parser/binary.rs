@@ -0,0 +1,226 @@
+//! Parsers for binary inputs.
+mod bits;
+mod number;
+mod varint;
+
+pub use bits::{Aligned, AlignedState, Bits, BitsState, Flag};
+pub use number::{Endian, Number, NumberState, Primitive};
+pub use varint::{zigzag, QuicVarint, Sleb128, Uleb128, VarintState};
+
+use crate::parser::{assert_parser, Map, Parser, ParserExt};
+use crate::stream::bits::{Align, Bytes};
+use crate::stream::Positioned;
+
+/// Parses a fixed-width number in the byte order.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::binary::{number, u16_be, f32_le, Endian};
+///
+/// let mut stream = stream::from_slice(b"\x12\x34\x00\x00\xc0\x3f\x78\x56\x34");
+/// assert_eq!(u16_be().parse(&mut stream).await, Ok(0x1234));
+/// assert_eq!(f32_le().parse(&mut stream).await, Ok(1.5));
+///
+/// // Not enough bytes, the error points to the bytes consumed.
+/// let err = number::<_, u32>(Endian::Little).parse(&mut stream).await.unwrap_err();
+/// assert!(matches!(err, somen::error::ParseError::Parser(e) if e.position == (6..9)));
+/// # });
+/// ```
+#[inline]
+pub fn number<I, T>(endian: Endian) -> Number<I, T>
+where
+    I: Positioned<Ok = u8> + ?Sized,
+    T: Primitive,
+{
+    assert_parser(Number::new(endian))
+}
+
+/// Parses a fixed-width number in big-endian.
+#[inline]
+pub fn be<I, T>() -> Number<I, T>
+where
+    I: Positioned<Ok = u8> + ?Sized,
+    T: Primitive,
+{
+    number(Endian::Big)
+}
+
+/// Parses a fixed-width number in little-endian.
+#[inline]
+pub fn le<I, T>() -> Number<I, T>
+where
+    I: Positioned<Ok = u8> + ?Sized,
+    T: Primitive,
+{
+    number(Endian::Little)
+}
+
+macro_rules! number_fn {
+    ($($t:ident: $be:ident, $le:ident;)*) => {
+        $(
+            #[doc = concat!("Parses a big-endian [`", stringify!($t), "`].")]
+            #[inline]
+            pub fn $be<I: Positioned<Ok = u8> + ?Sized>() -> Number<I, $t> {
+                be()
+            }
+
+            #[doc = concat!("Parses a little-endian [`", stringify!($t), "`].")]
+            #[inline]
+            pub fn $le<I: Positioned<Ok = u8> + ?Sized>() -> Number<I, $t> {
+                le()
+            }
+        )*
+    };
+}
+
+number_fn! {
+    u16: u16_be, u16_le;
+    u32: u32_be, u32_le;
+    u64: u64_be, u64_le;
+    u128: u128_be, u128_le;
+    i16: i16_be, i16_le;
+    i32: i32_be, i32_le;
+    i64: i64_be, i64_le;
+    i128: i128_be, i128_le;
+    f32: f32_be, f32_le;
+    f64: f64_be, f64_le;
+}
+
+/// Parses an unsigned LEB128 integer up to 64 bits.
+///
+/// Values overflowing 64 bits and over-long encodings (with trailing `0x80`s) are rejected, the
+/// latter can be allowed by [`Uleb128::canonical`].
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::binary::{quic_varint, sleb128, uleb128, zigzag_varint};
+///
+/// let mut stream = stream::from_slice(b"\xe5\x8e\x26\xc0\xbb\x78\x03\x7b\xbd");
+/// assert_eq!(uleb128().parse(&mut stream).await, Ok(624485));
+/// assert_eq!(sleb128().parse(&mut stream).await, Ok(-123456));
+/// assert_eq!(zigzag_varint().parse(&mut stream).await, Ok(-2));
+/// assert_eq!(quic_varint().parse(&mut stream).await, Ok(15293));
+///
+/// // Over-long encodings are errors.
+/// let mut stream = stream::from_slice(b"\x81\x00");
+/// assert!(uleb128().parse(&mut stream).await.is_err());
+/// // And so are values overflowing 64 bits.
+/// let mut stream = stream::from_slice(&[0xff; 11]);
+/// assert!(uleb128().parse(&mut stream).await.is_err());
+/// # });
+/// ```
+#[inline]
+pub fn uleb128<I>() -> Uleb128<I>
+where
+    I: Positioned<Ok = u8> + ?Sized,
+{
+    assert_parser(Uleb128::new())
+}
+
+/// Parses a signed LEB128 integer up to 64 bits.
+///
+/// Over-long encodings are rejected like [`uleb128`].
+#[inline]
+pub fn sleb128<I>() -> Sleb128<I>
+where
+    I: Positioned<Ok = u8> + ?Sized,
+{
+    assert_parser(Sleb128::new())
+}
+
+/// Parses a varint of Protocol Buffers, which is the same encoding as [`uleb128`].
+#[inline]
+pub fn varint<I>() -> Uleb128<I>
+where
+    I: Positioned<Ok = u8> + ?Sized,
+{
+    uleb128()
+}
+
+/// Parses a zigzag-encoded varint of Protocol Buffers (`sint32` and `sint64`).
+#[inline]
+pub fn zigzag_varint<I>() -> Map<Uleb128<I>, fn(u64) -> i64>
+where
+    I: Positioned<Ok = u8> + ?Sized,
+{
+    varint().map(zigzag as fn(u64) -> i64)
+}
+
+/// Parses a variable-length integer of QUIC (RFC 9000), which is prefixed by 2 bits of its length.
+///
+/// Over-long encodings are allowed by the specification, and can be rejected by
+/// [`QuicVarint::canonical`].
+#[inline]
+pub fn quic_varint<I>() -> QuicVarint<I>
+where
+    I: Positioned<Ok = u8> + ?Sized,
+{
+    assert_parser(QuicVarint::new())
+}
+
+/// Parses `count` bits as an unsigned integer, the first bit is the most significant.
+///
+/// # Panics
+/// Panics if `count` is greater than 64.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::binary::{aligned, bits, flag, u16_be};
+/// use somen::stream::bits::BitPosition;
+///
+/// // The second line of a DNS header: QR, OPCODE, AA, TC, RD, RA, Z, RCODE and then QDCOUNT.
+/// let mut header = flag()
+///     .and(bits(4))
+///     .and(flag())
+///     .skip(bits(6))
+///     .and(bits(4))
+///     .and(aligned(u16_be()));
+///
+/// let mut stream = stream::from_slice(b"\x85\x83\x00\x01").bits();
+/// assert_eq!(header.parse(&mut stream).await, Ok(((((true, 0), true), 3), 1)));
+/// assert_eq!(stream.position(), BitPosition { byte: 4, bit: 0 });
+///
+/// // Not enough bits, the error points to the bits consumed.
+/// let mut stream = stream::from_slice(b"\xff").bits();
+/// let err = bits(12).parse(&mut stream).await.unwrap_err();
+/// assert!(matches!(
+///     err,
+///     somen::error::ParseError::Parser(e)
+///         if e.position == (BitPosition { byte: 0, bit: 0 }..BitPosition { byte: 1, bit: 0 })
+/// ));
+/// # });
+/// ```
+#[inline]
+pub fn bits<I>(count: u8) -> Bits<I>
+where
+    I: Positioned<Ok = bool> + ?Sized,
+{
+    assert_parser(Bits::new(count))
+}
+
+/// Parses a single bit as a boolean flag.
+#[inline]
+pub fn flag<I>() -> Flag<I>
+where
+    I: Positioned<Ok = bool> + ?Sized,
+{
+    assert_parser(Flag::new())
+}
+
+/// Skips the remaining bits of the current byte, and parses the following bytes by `inner`.
+///
+/// The stream is byte-aligned again after `inner` succeeds, so bit-level parsers can follow.
+#[inline]
+pub fn aligned<P, I>(inner: P) -> Aligned<P>
+where
+    P: Parser<Bytes<I>>,
+    I: Align + Positioned + ?Sized,
+{
+    assert_parser(Aligned::new(inner))
+}
@@ -0,0 +1,180 @@
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::Parser;
+use crate::stream::bits::{Align, Bytes};
+use crate::stream::Positioned;
+
+/// A parser for function [`bits`].
+///
+/// [`bits`]: crate::parser::binary::bits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bits<I: ?Sized> {
+    count: u8,
+    _phantom: PhantomData<I>,
+}
+
+impl<I: ?Sized> Bits<I> {
+    /// Creates a new instance.
+    ///
+    /// # Panics
+    /// Panics if `count` is greater than 64.
+    #[inline]
+    pub fn new(count: u8) -> Self {
+        assert!(count <= 64, "cannot parse more than 64 bits at once");
+        Self {
+            count,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+crate::parser_state! {
+    pub struct BitsState<I> {
+        value: u64,
+        count: u8,
+        #[opt(set = set_start)]
+        start: I::Locator,
+    }
+}
+
+impl<I> Parser<I> for Bits<I>
+where
+    I: Positioned<Ok = bool> + ?Sized,
+{
+    type Output = u64;
+    type State = BitsState<I>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        state.set_start(|| input.position());
+
+        while state.count < self.count {
+            match ready!(input.as_mut().try_poll_next(cx)?) {
+                Some(bit) => {
+                    // `count` is less than 64 here, so the shift never overflows.
+                    state.value = state.value << 1 | u64::from(bit);
+                    state.count += 1;
+                }
+                None => {
+                    return Poll::Ready(Ok(Status::Failure(
+                        Error {
+                            expects: Expects::from("a bit field"),
+                            position: state.start()..input.position(),
+                        },
+                        false,
+                    )))
+                }
+            }
+        }
+
+        Poll::Ready(Ok(Status::Success(state.value, None)))
+    }
+}
+
+/// A parser for function [`flag`].
+///
+/// [`flag`]: crate::parser::binary::flag
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Flag<I: ?Sized>(PhantomData<I>);
+
+impl<I: ?Sized> Default for Flag<I> {
+    #[inline]
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<I: ?Sized> Flag<I> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<I> Parser<I> for Flag<I>
+where
+    I: Positioned<Ok = bool> + ?Sized,
+{
+    type Output = bool;
+    type State = ();
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        _state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        let start = input.position();
+        Poll::Ready(Ok(match ready!(input.as_mut().try_poll_next(cx)?) {
+            Some(bit) => Status::Success(bit, None),
+            None => Status::Failure(
+                Error {
+                    expects: Expects::from("a flag"),
+                    position: start..input.position(),
+                },
+                false,
+            ),
+        }))
+    }
+}
+
+/// A parser for function [`aligned`].
+///
+/// [`aligned`]: crate::parser::binary::aligned
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Aligned<P> {
+    inner: P,
+}
+
+impl<P> Aligned<P> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: P) -> Self {
+        Self { inner }
+    }
+
+    /// Extracts the inner parser.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+/// The state of [`Aligned`].
+#[derive(Debug, Default)]
+pub struct AlignedState<T> {
+    inner: T,
+    aligned: bool,
+}
+
+impl<P, I> Parser<I> for Aligned<P>
+where
+    P: Parser<Bytes<I>>,
+    I: Align + Positioned + ?Sized,
+{
+    type Output = P::Output;
+    type State = AlignedState<P::State>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        if !state.aligned {
+            input.as_mut().align();
+            state.aligned = true;
+        }
+        self.inner
+            .poll_parse(Bytes::from_pin(input), cx, &mut state.inner)
+    }
+}
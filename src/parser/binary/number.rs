@@ -0,0 +1,128 @@
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::Parser;
+use crate::stream::Positioned;
+
+/// Byte orders of numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// The most significant byte comes first.
+    Big,
+    /// The least significant byte comes first.
+    Little,
+}
+
+/// Primitive numbers which can be parsed from fixed-width bytes.
+pub trait Primitive: Sized {
+    /// The number of bytes.
+    const SIZE: usize;
+
+    /// Describes the number for error messages.
+    const NAME: [&'static str; 2];
+
+    /// Converts bytes into the number, `bytes` must be [`SIZE`] bytes long.
+    ///
+    /// [`SIZE`]: Self::SIZE
+    fn from_bytes(bytes: &[u8], endian: Endian) -> Self;
+}
+
+macro_rules! primitive_impl {
+    ($($t:ident),*) => {
+        $(
+            impl Primitive for $t {
+                const SIZE: usize = core::mem::size_of::<$t>();
+                const NAME: [&'static str; 2] = [
+                    concat!("a big-endian ", stringify!($t)),
+                    concat!("a little-endian ", stringify!($t)),
+                ];
+
+                #[inline]
+                fn from_bytes(bytes: &[u8], endian: Endian) -> Self {
+                    let bytes = bytes.try_into().unwrap();
+                    match endian {
+                        Endian::Big => $t::from_be_bytes(bytes),
+                        Endian::Little => $t::from_le_bytes(bytes),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+primitive_impl! { u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64 }
+
+/// A parser for function [`number`].
+///
+/// [`number`]: crate::parser::binary::number
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Number<I: ?Sized, T> {
+    endian: Endian,
+    _phantom: PhantomData<I>,
+    _output: PhantomData<T>,
+}
+
+impl<I: ?Sized, T> Number<I, T> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(endian: Endian) -> Self {
+        Self {
+            endian,
+            _phantom: PhantomData,
+            _output: PhantomData,
+        }
+    }
+}
+
+crate::parser_state! {
+    pub struct NumberState<I> {
+        buf: [u8; 16],
+        len: usize,
+        #[opt(set = set_start)]
+        start: I::Locator,
+    }
+}
+
+impl<I, T> Parser<I> for Number<I, T>
+where
+    I: Positioned<Ok = u8> + ?Sized,
+    T: Primitive,
+{
+    type Output = T;
+    type State = NumberState<I>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        state.set_start(|| input.position());
+
+        while state.len < T::SIZE {
+            match ready!(input.as_mut().try_poll_next(cx)?) {
+                Some(b) => {
+                    state.buf[state.len] = b;
+                    state.len += 1;
+                }
+                None => {
+                    return Poll::Ready(Ok(Status::Failure(
+                        Error {
+                            expects: Expects::from(T::NAME[self.endian as usize]),
+                            position: state.start()..input.position(),
+                        },
+                        false,
+                    )))
+                }
+            }
+        }
+
+        Poll::Ready(Ok(Status::Success(
+            T::from_bytes(&state.buf[..T::SIZE], self.endian),
+            None,
+        )))
+    }
+}
@@ -0,0 +1,232 @@
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::Parser;
+use crate::stream::Positioned;
+
+macro_rules! varint_parser {
+    ($(#[$attrs:meta])* $name:ident, $canonical:expr) => {
+        $(#[$attrs])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct $name<I: ?Sized> {
+            canonical: bool,
+            _phantom: PhantomData<I>,
+        }
+
+        impl<I: ?Sized> Default for $name<I> {
+            #[inline]
+            fn default() -> Self {
+                Self {
+                    canonical: $canonical,
+                    _phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<I: ?Sized> $name<I> {
+            /// Creates a new instance.
+            #[inline]
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            #[doc = concat!(
+                "Rejects or accepts over-long (non-minimal) encodings. (default: `",
+                stringify!($canonical),
+                "`)"
+            )]
+            #[inline]
+            pub fn canonical(mut self, canonical: bool) -> Self {
+                self.canonical = canonical;
+                self
+            }
+        }
+    };
+}
+
+varint_parser! {
+    /// A parser for function [`uleb128`].
+    ///
+    /// [`uleb128`]: crate::parser::binary::uleb128
+    Uleb128, true
+}
+
+varint_parser! {
+    /// A parser for function [`sleb128`].
+    ///
+    /// [`sleb128`]: crate::parser::binary::sleb128
+    Sleb128, true
+}
+
+varint_parser! {
+    /// A parser for function [`quic_varint`].
+    ///
+    /// [`quic_varint`]: crate::parser::binary::quic_varint
+    QuicVarint, false
+}
+
+crate::parser_state! {
+    pub struct VarintState<I> {
+        value: u64,
+        count: usize,
+        prev: u8,
+        #[opt(set = set_start)]
+        start: I::Locator,
+    }
+}
+
+macro_rules! fail {
+    ($expects:expr, $state:expr, $input:expr) => {
+        return Poll::Ready(Ok(Status::Failure(
+            Error {
+                expects: Expects::from($expects),
+                position: $state.start()..$input.position(),
+            },
+            false,
+        )))
+    };
+}
+
+impl<I> Parser<I> for Uleb128<I>
+where
+    I: Positioned<Ok = u8> + ?Sized,
+{
+    type Output = u64;
+    type State = VarintState<I>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        state.set_start(|| input.position());
+
+        loop {
+            let b = match ready!(input.as_mut().try_poll_next(cx)?) {
+                Some(b) => b,
+                None => fail!("a LEB128 integer", state, input),
+            };
+
+            let shift = state.count * 7;
+            state.count += 1;
+            let low = u64::from(b & 0x7f);
+            if shift >= 64 || (shift == 63 && low > 1) {
+                fail!("an integer within 64 bits", state, input);
+            }
+            state.value |= low << shift;
+
+            if b & 0x80 == 0 {
+                if self.canonical && state.count > 1 && b == 0 {
+                    fail!("a minimally encoded integer", state, input);
+                }
+                return Poll::Ready(Ok(Status::Success(state.value, None)));
+            }
+        }
+    }
+}
+
+impl<I> Parser<I> for Sleb128<I>
+where
+    I: Positioned<Ok = u8> + ?Sized,
+{
+    type Output = i64;
+    type State = VarintState<I>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        state.set_start(|| input.position());
+
+        loop {
+            let b = match ready!(input.as_mut().try_poll_next(cx)?) {
+                Some(b) => b,
+                None => fail!("a LEB128 integer", state, input),
+            };
+
+            let shift = state.count * 7;
+            state.count += 1;
+            let low = u64::from(b & 0x7f);
+            // The last byte of 64 bits only has the sign bit, the rest must be sign extension.
+            if shift >= 64 || (shift == 63 && low != 0 && low != 0x7f) {
+                fail!("an integer within 64 bits", state, input);
+            }
+            state.value |= low << shift;
+
+            if b & 0x80 == 0 {
+                // The byte only extends the sign of the previous byte.
+                if self.canonical
+                    && state.count > 1
+                    && ((b == 0 && state.prev & 0x40 == 0) || (b == 0x7f && state.prev & 0x40 != 0))
+                {
+                    fail!("a minimally encoded integer", state, input);
+                }
+                let mut value = state.value as i64;
+                if shift + 7 < 64 && b & 0x40 != 0 {
+                    value |= -1 << (shift + 7);
+                }
+                return Poll::Ready(Ok(Status::Success(value, None)));
+            }
+            state.prev = b;
+        }
+    }
+}
+
+impl<I> Parser<I> for QuicVarint<I>
+where
+    I: Positioned<Ok = u8> + ?Sized,
+{
+    type Output = u64;
+    type State = VarintState<I>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        state.set_start(|| input.position());
+
+        loop {
+            let b = match ready!(input.as_mut().try_poll_next(cx)?) {
+                Some(b) => b,
+                None => fail!("a QUIC variable-length integer", state, input),
+            };
+
+            if state.count == 0 {
+                state.prev = b >> 6;
+                state.value = u64::from(b & 0x3f);
+            } else {
+                state.value = (state.value << 8) | u64::from(b);
+            }
+            state.count += 1;
+
+            // The first two bits indicate the length: 1, 2, 4 or 8 bytes.
+            let len = 1 << state.prev;
+            if state.count == len {
+                let min = match len {
+                    1 => 0,
+                    2 => 1 << 6,
+                    4 => 1 << 14,
+                    _ => 1 << 30,
+                };
+                if self.canonical && state.value < min {
+                    fail!("a minimally encoded integer", state, input);
+                }
+                return Poll::Ready(Ok(Status::Success(state.value, None)));
+            }
+        }
+    }
+}
+
+/// Decodes a zigzag-encoded integer, which maps `0, 1, 2, 3, ...` to `0, -1, 1, -2, ...`.
+#[inline]
+pub fn zigzag(n: u64) -> i64 {
+    ((n >> 1) as i64) ^ -((n & 1) as i64)
+}
@@ -1,17 +1,23 @@
 //! Parser combinators.
 mod choice;
+mod dispatch;
 mod fail;
+mod framing;
 mod opt;
 mod peek;
+mod permutation;
 mod prefix;
 mod skip;
 mod then;
 mod tuples;
 
 pub use choice::{ChoiceParser, ChoiceIterableParser, Or};
+pub use dispatch::{Dispatch, DispatchState};
 pub use fail::Fail;
+pub use framing::{CountPrefixed, LengthPrefixed};
 pub use opt::Opt;
 pub use peek::Peek;
+pub use permutation::{Permutation, PermutationState};
 pub use prefix::Prefix;
 pub use skip::Skip;
-pub use then::{Then, TryThen};
+pub use then::{Then, ThenCtx, TryThen};
@@ -2,7 +2,12 @@ use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::ready;
 
-use crate::error::{Error, PolledResult, Status};
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::{merge_expects, First};
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::utils::{merge_errors, EitherState};
 use crate::parser::Parser;
@@ -297,3 +302,50 @@ choice_tuple! { T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13 }
 choice_tuple! { T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14 }
 choice_tuple! { T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15 }
 choice_tuple! { T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, Q: Describe> Describe for Or<P, Q> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::choice([
+            self.left.describe_with(describer),
+            self.right.describe_with(describer),
+        ])
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>, Q: Generate<T>> Generate<T> for Or<P, Q> {
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        // Tries the other alternative if the picked one fails.
+        if gen.below(self.choices()) < self.left.choices() {
+            gen.attempt(&mut self.left)
+                .or_else(|_| self.right.generate(gen))
+        } else {
+            gen.attempt(&mut self.right)
+                .or_else(|_| self.left.generate(gen))
+        }
+    }
+
+    #[inline]
+    fn choices(&self) -> usize {
+        self.left.choices() + self.right.choices()
+    }
+}
+
+impl<T, P: First<T>, Q: First<T>> First<T> for Or<P, Q> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.left.first(token) || self.right.first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.left.nullable() || self.right.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        merge_expects(self.left.first_expects(), self.right.first_expects())
+    }
+}
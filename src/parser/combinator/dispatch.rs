@@ -0,0 +1,201 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::{merge_expects, First};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::utils::merge_errors;
+use crate::parser::Parser;
+use crate::stream::Input;
+
+/// A parser for function [`dispatch`].
+///
+/// [`dispatch`]: crate::parser::dispatch
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dispatch<T> {
+    inner: T,
+}
+
+impl<T> Dispatch<T> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Extracts the inner parsers.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+crate::parser_state! {
+    pub struct DispatchState<I: Input; S: Default> {
+        states: S,
+        viable: Option<u32>,
+        index: usize,
+        #[opt]
+        marker: I::Marker,
+        #[opt(set = set_start)]
+        start: I::Locator,
+        error: Option<Error<I::Locator>>,
+    }
+}
+
+macro_rules! dispatch_tuple {
+    ($($idx:tt $T:ident),+) => {
+        impl<I, T1, $($T),+> Parser<I> for Dispatch<(T1, $($T,)+)>
+        where
+            I: Input + ?Sized,
+            T1: Parser<I> + First<I::Ok>,
+            $($T: Parser<I, Output = T1::Output> + First<I::Ok>,)+
+        {
+            type Output = T1::Output;
+            type State = DispatchState<I, (T1::State, $($T::State,)+)>;
+
+            fn poll_parse(
+                &mut self,
+                mut input: Pin<&mut I>,
+                cx: &mut Context<'_>,
+                state: &mut Self::State,
+            ) -> PolledResult<Self::Output, I> {
+                state.set_start(|| input.position());
+
+                // Peeks the first token to find viable branches.
+                if state.viable.is_none() {
+                    if state.marker.is_none() {
+                        state.marker = Some(input.as_mut().mark()?);
+                    }
+                    let token = ready!(input.as_mut().try_poll_next(cx)?);
+                    let end = input.position();
+                    input.as_mut().rewind(state.marker())?;
+
+                    let mut viable = 0;
+                    if dispatch_tuple!(@viable self.inner.0, token) {
+                        viable |= 1;
+                    }
+                    $(
+                        if dispatch_tuple!(@viable self.inner.$idx, token) {
+                            viable |= 1 << $idx;
+                        }
+                    )+
+
+                    if viable == 0 {
+                        let mut expects = self.inner.0.first_expects();
+                        $(expects = merge_expects(expects, self.inner.$idx.first_expects());)+
+                        return Poll::Ready(Ok(Status::Failure(
+                            Error {
+                                expects: expects.unwrap_or_else(|| Expects::from("<dispatch>")),
+                                position: state.start()..end,
+                            },
+                            false,
+                        )));
+                    }
+                    state.viable = Some(viable);
+                    state.index = viable.trailing_zeros() as usize;
+                }
+
+                let viable = state.viable.unwrap();
+                loop {
+                    // The last viable branch is parsed without marking, like the only one.
+                    let last = viable >> (state.index + 1) == 0;
+                    if !last && state.marker.is_none() {
+                        state.marker = Some(input.as_mut().mark()?);
+                    }
+
+                    let status = ready!(match state.index {
+                        0 => self.inner.0.poll_parse(input.as_mut(), cx, &mut state.states.0)?,
+                        $(
+                            $idx => self
+                                .inner
+                                .$idx
+                                .poll_parse(input.as_mut(), cx, &mut state.states.$idx)?,
+                        )+
+                        _ => unreachable!(),
+                    });
+
+                    match status {
+                        Status::Failure(err, false)
+                            if !last && err.rewindable(state.start.as_ref().unwrap()) =>
+                        {
+                            input.as_mut().rewind(state.marker())?;
+                            merge_errors(&mut state.error, Some(err));
+                            state.index +=
+                                1 + (viable >> (state.index + 1)).trailing_zeros() as usize;
+                        }
+                        status => {
+                            if let Some(marker) = state.marker.take() {
+                                input.as_mut().drop_marker(marker)?;
+                            }
+                            return Poll::Ready(Ok(match status {
+                                Status::Success(val, err) => {
+                                    merge_errors(&mut state.error, err);
+                                    Status::Success(val, state.error())
+                                }
+                                Status::Failure(err, false) => {
+                                    merge_errors(&mut state.error, Some(err));
+                                    Status::Failure(state.error().unwrap(), false)
+                                }
+                                Status::Failure(err, true) => Status::Failure(err, true),
+                            }));
+                        }
+                    }
+                }
+            }
+        }
+
+        impl<T, T1, $($T),+> First<T> for Dispatch<(T1, $($T,)+)>
+        where
+            T1: First<T>,
+            $($T: First<T>,)+
+        {
+            #[inline]
+            fn first(&mut self, token: &T) -> bool {
+                self.inner.0.first(token) $(|| self.inner.$idx.first(token))+
+            }
+
+            #[inline]
+            fn nullable(&self) -> bool {
+                self.inner.0.nullable() $(|| self.inner.$idx.nullable())+
+            }
+
+            fn first_expects(&self) -> Option<Expects> {
+                let mut expects = self.inner.0.first_expects();
+                $(expects = merge_expects(expects, self.inner.$idx.first_expects());)+
+                expects
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<T1: Describe, $($T: Describe),+> Describe for Dispatch<(T1, $($T,)+)> {
+            fn describe_with(&self, describer: &mut Describer) -> Grammar {
+                Grammar::choice([
+                    self.inner.0.describe_with(describer),
+                    $(self.inner.$idx.describe_with(describer)),+
+                ])
+            }
+        }
+    };
+
+    (@viable $p:expr, $token:ident) => {
+        match &$token {
+            Some(token) => $p.nullable() || $p.first(token),
+            None => $p.nullable(),
+        }
+    };
+}
+
+dispatch_tuple! { 1 T2 }
+dispatch_tuple! { 1 T2, 2 T3 }
+dispatch_tuple! { 1 T2, 2 T3, 3 T4 }
+dispatch_tuple! { 1 T2, 2 T3, 3 T4, 4 T5 }
+dispatch_tuple! { 1 T2, 2 T3, 3 T4, 4 T5, 5 T6 }
+dispatch_tuple! { 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7 }
+dispatch_tuple! { 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8 }
+dispatch_tuple! { 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9 }
+dispatch_tuple! { 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T10 }
+dispatch_tuple! { 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T10, 10 T11 }
+dispatch_tuple! { 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T10, 10 T11, 11 T12 }
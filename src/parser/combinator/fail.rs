@@ -3,8 +3,12 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, Expects, PolledResult, Status};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::Parser;
 use crate::stream::Input;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 
 /// A parser for method [`fail`].
 ///
@@ -79,3 +83,11 @@ where
         ))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe> Describe for Fail<P> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::NegativeLookahead(Box::new(self.inner.describe_with(describer)))
+    }
+}
@@ -0,0 +1,322 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Error, Expects, PolledResult, Status};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::iterable::IterableParser;
+use crate::parser::utils::{merge_errors, EitherState};
+use crate::parser::Parser;
+use crate::stream::window::Window;
+use crate::stream::Positioned;
+
+/// A parser for function [`length_prefixed`].
+///
+/// [`length_prefixed`]: crate::parser::length_prefixed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LengthPrefixed<L, P> {
+    len: L,
+    inner: P,
+}
+
+impl<L, P> LengthPrefixed<L, P> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(len: L, inner: P) -> Self {
+        Self { len, inner }
+    }
+
+    /// Extracts the inner parser.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+crate::parser_state! {
+    pub struct LengthPrefixedState<I, L: Parser; T: Default> {
+        inner: EitherState<L::State, T>,
+        #[opt]
+        outer: Option<usize>,
+        #[opt(set = set_start)]
+        start: I::Locator,
+        error: Option<Error<I::Locator>>,
+    }
+}
+
+impl<I, L, T> LengthPrefixedState<I, L, T>
+where
+    I: Positioned + Window + ?Sized,
+    L: Parser<I>,
+    L::Output: TryInto<usize>,
+    T: Default,
+{
+    /// Parses the length and opens a window.
+    fn poll_open(
+        &mut self,
+        len: &mut L,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+    ) -> PolledResult<(), I> {
+        self.set_start(|| input.position());
+        if let EitherState::Left(inner) = &mut self.inner {
+            match ready!(len.poll_parse(input.as_mut(), cx, inner)?) {
+                Status::Success(val, err) => {
+                    self.error = err;
+                    let fits = |n: &usize| input.remaining().is_none_or(|rem| *n <= rem);
+                    match val.try_into() {
+                        Ok(n) if fits(&n) => {
+                            self.outer = Some(input.as_mut().open_window(n));
+                            self.inner = EitherState::new_right();
+                        }
+                        Ok(_) => return self.fail("a complete frame", input),
+                        Err(_) => return self.fail("a valid length", input),
+                    }
+                }
+                Status::Failure(err, exclusive) => {
+                    return Poll::Ready(Ok(Status::Failure(err, exclusive)))
+                }
+            }
+        }
+        Poll::Ready(Ok(Status::Success((), None)))
+    }
+
+    /// Checks that the window is fully consumed, and closes it.
+    fn close(&mut self, mut input: Pin<&mut I>) -> Option<Error<I::Locator>> {
+        let consumed = input.remaining() == Some(0);
+        let pos = input.position();
+        input.as_mut().close_window(self.outer());
+        if consumed {
+            None
+        } else {
+            Some(Error {
+                expects: Expects::from("the end of the frame"),
+                position: pos..input.position(),
+            })
+        }
+    }
+
+    #[inline]
+    fn fail(&mut self, expects: &'static str, input: Pin<&mut I>) -> PolledResult<(), I> {
+        Poll::Ready(Ok(Status::Failure(
+            Error {
+                expects: Expects::from(expects),
+                position: self.start()..input.position(),
+            },
+            true,
+        )))
+    }
+}
+
+impl<L, P, I> Parser<I> for LengthPrefixed<L, P>
+where
+    L: Parser<I>,
+    L::Output: TryInto<usize>,
+    P: Parser<I>,
+    I: Positioned + Window + ?Sized,
+{
+    type Output = P::Output;
+    type State = LengthPrefixedState<I, L, P::State>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        if let Status::Failure(err, exclusive) =
+            ready!(state.poll_open(&mut self.len, input.as_mut(), cx)?)
+        {
+            return Poll::Ready(Ok(Status::Failure(err, exclusive)));
+        }
+
+        let status = ready!(self
+            .inner
+            .poll_parse(input.as_mut(), cx, state.inner.right())?);
+        let unconsumed = state.close(input);
+        Poll::Ready(Ok(match (status, unconsumed) {
+            (Status::Success(val, err), None) => {
+                merge_errors(&mut state.error, err);
+                Status::Success(val, state.error())
+            }
+            (Status::Success(_, _), Some(err)) => Status::Failure(err, true),
+            (Status::Failure(err, false), _) => {
+                merge_errors(&mut state.error, Some(err));
+                Status::Failure(state.error().unwrap(), false)
+            }
+            (Status::Failure(err, true), _) => Status::Failure(err, true),
+        }))
+    }
+}
+
+impl<L, P, I> IterableParser<I> for LengthPrefixed<L, P>
+where
+    L: Parser<I>,
+    L::Output: TryInto<usize>,
+    P: IterableParser<I>,
+    I: Positioned + Window + ?Sized,
+{
+    type Item = P::Item;
+    type State = LengthPrefixedState<I, L, P::State>;
+
+    fn poll_parse_next(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Option<Self::Item>, I> {
+        if let Status::Failure(err, exclusive) =
+            ready!(state.poll_open(&mut self.len, input.as_mut(), cx)?)
+        {
+            return Poll::Ready(Ok(Status::Failure(err, exclusive)));
+        }
+
+        let status = ready!(self
+            .inner
+            .poll_parse_next(input.as_mut(), cx, state.inner.right())?);
+        Poll::Ready(Ok(match status {
+            Status::Success(Some(val), err) => {
+                merge_errors(&mut state.error, err);
+                Status::Success(Some(val), state.error())
+            }
+            Status::Success(None, err) => match state.close(input) {
+                None => {
+                    merge_errors(&mut state.error, err);
+                    Status::Success(None, state.error())
+                }
+                Some(err) => Status::Failure(err, true),
+            },
+            Status::Failure(err, exclusive) => {
+                state.close(input);
+                if exclusive {
+                    Status::Failure(err, true)
+                } else {
+                    merge_errors(&mut state.error, Some(err));
+                    Status::Failure(state.error().unwrap(), false)
+                }
+            }
+        }))
+    }
+}
+
+/// A iterable parser for function [`count_prefixed`].
+///
+/// [`count_prefixed`]: crate::parser::count_prefixed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CountPrefixed<C, P> {
+    count: C,
+    inner: P,
+}
+
+impl<C, P> CountPrefixed<C, P> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(count: C, inner: P) -> Self {
+        Self { count, inner }
+    }
+
+    /// Extracts the inner parser.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+crate::parser_state! {
+    pub struct CountPrefixedState<I, C: Parser, P: Parser> {
+        inner: EitherState<C::State, P::State>,
+        remaining: usize,
+        #[opt(set = set_start)]
+        start: I::Locator,
+        error: Option<Error<I::Locator>>,
+    }
+}
+
+impl<C, P, I> IterableParser<I> for CountPrefixed<C, P>
+where
+    C: Parser<I>,
+    C::Output: TryInto<usize>,
+    P: Parser<I>,
+    I: Positioned + ?Sized,
+{
+    type Item = P::Output;
+    type State = CountPrefixedState<I, C, P>;
+
+    fn poll_parse_next(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Option<Self::Item>, I> {
+        state.set_start(|| input.position());
+        if let EitherState::Left(inner) = &mut state.inner {
+            match ready!(self.count.poll_parse(input.as_mut(), cx, inner)?) {
+                Status::Success(val, err) => match val.try_into() {
+                    Ok(n) => {
+                        state.remaining = n;
+                        state.inner = EitherState::new_right();
+                        state.error = err;
+                    }
+                    Err(_) => {
+                        return Poll::Ready(Ok(Status::Failure(
+                            Error {
+                                expects: Expects::from("a valid count"),
+                                position: state.start()..input.position(),
+                            },
+                            true,
+                        )))
+                    }
+                },
+                Status::Failure(err, exclusive) => {
+                    return Poll::Ready(Ok(Status::Failure(err, exclusive)))
+                }
+            }
+        }
+
+        if state.remaining == 0 {
+            return Poll::Ready(Ok(Status::Success(None, state.error())));
+        }
+
+        Poll::Ready(Ok(
+            match ready!(self
+                .inner
+                .poll_parse(input.as_mut(), cx, state.inner.right())?)
+            {
+                Status::Success(val, err) => {
+                    state.remaining -= 1;
+                    state.inner = EitherState::new_right();
+                    merge_errors(&mut state.error, err);
+                    Status::Success(Some(val), state.error())
+                }
+                Status::Failure(err, false) => {
+                    merge_errors(&mut state.error, Some(err));
+                    Status::Failure(state.error().unwrap(), false)
+                }
+                Status::Failure(err, true) => Status::Failure(err, true),
+            },
+        ))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<L: Describe, P: Describe> Describe for LengthPrefixed<L, P> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::sequence([
+            self.len.describe_with(describer),
+            self.inner.describe_with(describer),
+        ])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Describe, P: Describe> Describe for CountPrefixed<C, P> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::sequence([
+            self.count.describe_with(describer),
+            Grammar::repeat(self.inner.describe_with(describer), ..),
+        ])
+    }
+}
@@ -2,7 +2,12 @@ use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::ready;
 
-use crate::error::{PolledResult, Status};
+use crate::error::{Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::Parser;
 use crate::stream::Input;
@@ -125,3 +130,39 @@ where
         ))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe> Describe for Opt<P> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::repeat(self.inner.describe_with(describer), ..=1)
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>> Generate<T> for Opt<P> {
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        // Generates nothing if the inner parser fails.
+        if gen.below(2) == 0 {
+            let _ = gen.attempt(&mut self.inner);
+        }
+        Ok(())
+    }
+}
+
+impl<T, P: First<T>> First<T> for Opt<P> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.inner.first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        self.inner.first_expects()
+    }
+}
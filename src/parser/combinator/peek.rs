@@ -3,8 +3,12 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{PolledResult, Status};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::Parser;
 use crate::stream::Input;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 
 /// A parser for method [`peek`].
 ///
@@ -66,3 +70,11 @@ where
         ))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe> Describe for Peek<P> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::Lookahead(Box::new(self.inner.describe_with(describer)))
+    }
+}
@@ -0,0 +1,169 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Error, PolledResult, Status};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::utils::merge_errors;
+use crate::parser::Parser;
+use crate::stream::Input;
+
+/// A parser for function [`permutation`].
+///
+/// [`permutation`]: crate::parser::permutation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Permutation<T> {
+    inner: T,
+}
+
+impl<T> Permutation<T> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Extracts the inner parsers.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+crate::parser_state! {
+    pub struct PermutationState<I: Input; S: Default, O: Default> {
+        states: S,
+        outputs: O,
+        fallbacks: O,
+        index: usize,
+        progress: bool,
+        #[opt]
+        marker: I::Marker,
+        #[opt(set = set_start)]
+        start: I::Locator,
+        error: Option<Error<I::Locator>>,
+    }
+}
+
+macro_rules! permutation_tuple {
+    ($($idx:tt $T:ident),+) => {
+        impl<I, $($T),+> Parser<I> for Permutation<($($T,)+)>
+        where
+            I: Input + ?Sized,
+            $($T: Parser<I>,)+
+        {
+            type Output = ($($T::Output,)+);
+            type State = PermutationState<
+                I,
+                ($($T::State,)+),
+                ($(Option<$T::Output>,)+)
+            >;
+
+            fn poll_parse(
+                &mut self,
+                mut input: Pin<&mut I>,
+                cx: &mut Context<'_>,
+                state: &mut Self::State,
+            ) -> PolledResult<Self::Output, I> {
+                loop {
+                    match state.index {
+                        $(
+                            $idx => {
+                                if state.outputs.$idx.is_none() {
+                                    if state.marker.is_none() {
+                                        state.marker = Some(input.as_mut().mark()?);
+                                    }
+                                    state.set_start(|| input.position());
+
+                                    let status = ready!(self.inner.$idx.poll_parse(
+                                        input.as_mut(),
+                                        cx,
+                                        &mut state.states.$idx,
+                                    )?);
+                                    state.states.$idx = Default::default();
+                                    match status {
+                                        // Consumed some tokens, the member is found.
+                                        Status::Success(val, _)
+                                            if input.position() != *state.start.as_ref().unwrap() =>
+                                        {
+                                            input.as_mut().drop_marker(state.marker())?;
+                                            state.outputs.$idx = Some(val);
+                                            state.progress = true;
+                                            state.error = None;
+                                        }
+                                        // Succeeded without consuming, keeps it as a default.
+                                        Status::Success(val, err) => {
+                                            input.as_mut().rewind(state.marker())?;
+                                            state.fallbacks.$idx = Some(val);
+                                            merge_errors(&mut state.error, err);
+                                        }
+                                        Status::Failure(err, false)
+                                            if err.rewindable(&state.start()) =>
+                                        {
+                                            input.as_mut().rewind(state.marker())?;
+                                            merge_errors(&mut state.error, Some(err));
+                                        }
+                                        Status::Failure(err, exclusive) => {
+                                            input.as_mut().drop_marker(state.marker())?;
+                                            return Poll::Ready(Ok(Status::Failure(
+                                                err, exclusive,
+                                            )));
+                                        }
+                                    }
+                                    state.start = None;
+                                }
+                                state.index += 1;
+                            }
+                        )+
+                        _ => {
+                            let done = true $(&& state.outputs.$idx.is_some())+;
+                            if done || !state.progress {
+                                break;
+                            }
+                            // Tries the remaining members again at the new position.
+                            state.index = 0;
+                            state.progress = false;
+                            state.fallbacks = Default::default();
+                        }
+                    }
+                }
+
+                let missing = false
+                    $(|| (state.outputs.$idx.is_none() && state.fallbacks.$idx.is_none()))+;
+                Poll::Ready(Ok(if missing {
+                    Status::Failure(state.error().unwrap(), false)
+                } else {
+                    Status::Success(
+                        ($(state.outputs.$idx.take().or(state.fallbacks.$idx.take()).unwrap(),)+),
+                        state.error(),
+                    )
+                }))
+            }
+        }
+
+        // Permutations can't be expressed in grammars, so approximated by repeating the
+        // alternatives.
+        #[cfg(feature = "alloc")]
+        impl<$($T: Describe),+> Describe for Permutation<($($T,)+)> {
+            fn describe_with(&self, describer: &mut Describer) -> Grammar {
+                let members = [$(self.inner.$idx.describe_with(describer)),+];
+                let len = members.len();
+                Grammar::repeat(Grammar::choice(members), ..=len)
+            }
+        }
+    };
+}
+
+permutation_tuple! { 0 T1 }
+permutation_tuple! { 0 T1, 1 T2 }
+permutation_tuple! { 0 T1, 1 T2, 2 T3 }
+permutation_tuple! { 0 T1, 1 T2, 2 T3, 3 T4 }
+permutation_tuple! { 0 T1, 1 T2, 2 T3, 3 T4, 4 T5 }
+permutation_tuple! { 0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6 }
+permutation_tuple! { 0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7 }
+permutation_tuple! { 0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8 }
+permutation_tuple! { 0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9 }
+permutation_tuple! { 0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T10 }
+permutation_tuple! { 0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T10, 10 T11 }
+permutation_tuple! { 0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T10, 10 T11, 11 T12 }
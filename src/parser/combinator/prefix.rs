@@ -2,7 +2,12 @@ use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::ready;
 
-use crate::error::{Error, PolledResult, Status};
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::{merge_expects, First};
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::utils::{merge_errors, EitherState};
 use crate::parser::Parser;
@@ -140,3 +145,44 @@ where
         self.inner.size_hint()
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, Q: Describe> Describe for Prefix<P, Q> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::sequence([
+            self.prefix.describe_with(describer),
+            self.inner.describe_with(describer),
+        ])
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>, Q: Generate<T>> Generate<T> for Prefix<P, Q> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        self.prefix.generate(gen)?;
+        self.inner.generate(gen)
+    }
+}
+
+impl<T, P: First<T>, Q: First<T>> First<T> for Prefix<P, Q> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.prefix.first(token) || (self.prefix.nullable() && self.inner.first(token))
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.prefix.nullable() && self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        if self.prefix.nullable() {
+            merge_expects(self.prefix.first_expects(), self.inner.first_expects())
+        } else {
+            self.prefix.first_expects()
+        }
+    }
+}
@@ -2,7 +2,12 @@ use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::ready;
 
-use crate::error::{Error, PolledResult, Status};
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::{merge_expects, First};
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::utils::{merge_errors, EitherState};
 use crate::parser::Parser;
@@ -134,3 +139,44 @@ where
         self.inner.size_hint()
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, Q: Describe> Describe for Skip<P, Q> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::sequence([
+            self.inner.describe_with(describer),
+            self.skipped.describe_with(describer),
+        ])
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>, Q: Generate<T>> Generate<T> for Skip<P, Q> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        self.inner.generate(gen)?;
+        self.skipped.generate(gen)
+    }
+}
+
+impl<T, P: First<T>, Q: First<T>> First<T> for Skip<P, Q> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.inner.first(token) || (self.inner.nullable() && self.skipped.first(token))
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.inner.nullable() && self.skipped.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        if self.inner.nullable() {
+            merge_expects(self.inner.first_expects(), self.skipped.first_expects())
+        } else {
+            self.inner.first_expects()
+        }
+    }
+}
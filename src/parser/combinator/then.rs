@@ -3,9 +3,12 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, Expects, PolledResult, Status};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::utils::{merge_errors, EitherState};
 use crate::parser::Parser;
+use crate::stream::context::Contextual;
 use crate::stream::Positioned;
 
 /// A parser for method [`then`].
@@ -317,3 +320,98 @@ where
             })
     }
 }
+
+/// A parser for method [`then_ctx`].
+///
+/// [`then_ctx`]: crate::parser::ParserExt::then_ctx
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThenCtx<P, F> {
+    inner: P,
+    f: F,
+}
+
+impl<P, F> ThenCtx<P, F> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: P, f: F) -> Self {
+        Self { inner, f }
+    }
+
+    /// Extracts the inner parser.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P, F, Q, I> Parser<I> for ThenCtx<P, F>
+where
+    P: Parser<I>,
+    F: FnMut(P::Output, &mut I::Ctx) -> Q,
+    Q: Parser<I>,
+    I: Positioned + Contextual + ?Sized,
+{
+    type Output = Q::Output;
+    type State = ThenState<I, P, Q>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        if let EitherState::Left(inner) = &mut state.inner {
+            match ready!(self.inner.poll_parse(input.as_mut(), cx, inner)?) {
+                Status::Success(val, err) => {
+                    state.parser = Some((self.f)(val, input.as_mut().ctx_mut()));
+                    state.inner = EitherState::new_right();
+                    state.error = err;
+                }
+                Status::Failure(err, exclusive) => {
+                    return Poll::Ready(Ok(Status::Failure(err, exclusive)))
+                }
+            }
+        }
+
+        state
+            .parser
+            .as_mut()
+            .unwrap()
+            .poll_parse(input, cx, state.inner.right())
+            .map_ok(|status| match status {
+                Status::Success(val, err) => {
+                    merge_errors(&mut state.error, err);
+                    Status::Success(val, state.error())
+                }
+                Status::Failure(err, false) => {
+                    merge_errors(&mut state.error, Some(err));
+                    Status::Failure(state.error().unwrap(), false)
+                }
+                exclusive => exclusive,
+            })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, F> Describe for Then<P, F> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::sequence([self.inner.describe_with(describer), Grammar::Opaque])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, F> Describe for TryThen<P, F> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::sequence([self.inner.describe_with(describer), Grammar::Opaque])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, F> Describe for ThenCtx<P, F> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::sequence([self.inner.describe_with(describer), Grammar::Opaque])
+    }
+}
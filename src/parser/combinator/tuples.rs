@@ -3,7 +3,12 @@ use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::ready;
 
-use crate::error::{Error, PolledResult, Status};
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::{merge_expects, First};
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::utils::merge_errors;
 use crate::parser::Parser;
 use crate::stream::Positioned;
@@ -61,6 +66,61 @@ macro_rules! tuple_parser {
                 )))
             }
         }
+
+        impl<T, $($t: First<T>),*> First<T> for ($($t),*,) {
+            fn first(&mut self, token: &T) -> bool {
+                #[allow(non_snake_case)]
+                let ($($t),*,) = self;
+                $(
+                    if $t.first(token) {
+                        return true;
+                    }
+                    if !$t.nullable() {
+                        return false;
+                    }
+                )*
+                false
+            }
+
+            fn nullable(&self) -> bool {
+                #[allow(non_snake_case)]
+                let ($($t),*,) = self;
+                true $(&& $t.nullable())*
+            }
+
+            fn first_expects(&self) -> Option<Expects> {
+                #[allow(non_snake_case)]
+                let ($($t),*,) = self;
+                let mut expects = None;
+                $(
+                    expects = merge_expects(expects, $t.first_expects());
+                    if !$t.nullable() {
+                        return expects;
+                    }
+                )*
+                expects
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<$($t: Describe),*> Describe for ($($t),*,) {
+            #[inline]
+            fn describe_with(&self, describer: &mut Describer) -> Grammar {
+                #[allow(non_snake_case)]
+                let ($($t),*,) = self;
+                Grammar::sequence([$($t.describe_with(describer)),*])
+            }
+        }
+
+        #[cfg(feature = "generate")]
+        impl<T, $($t: Generate<T>),*> Generate<T> for ($($t),*,) {
+            fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+                #[allow(non_snake_case)]
+                let ($($t),*,) = self;
+                $($t.generate(gen)?;)*
+                Ok(())
+            }
+        }
     };
 }
 
@@ -0,0 +1,75 @@
+//! Computes the first tokens of parsers statically.
+//!
+//! Parsers implementing [`First`] can be dispatched by the first token with [`dispatch`],
+//! instead of trying each alternative in order.
+//!
+//! [`dispatch`]: crate::parser::dispatch
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use crate::error::Expects;
+
+/// A trait for parsers which can tell the first token they accept.
+///
+/// The results must be conservative: [`first`] may return `true` for tokens the parser rejects
+/// (it only makes dispatching slower), but must not return `false` for tokens the parser
+/// accepts. The same applies to [`nullable`].
+///
+/// [`first`]: Self::first
+/// [`nullable`]: Self::nullable
+pub trait First<T> {
+    /// Returns `true` if the parser can start with `token`.
+    fn first(&mut self, token: &T) -> bool;
+
+    /// Returns `true` if the parser can succeed without consuming any tokens.
+    fn nullable(&self) -> bool;
+
+    /// Returns expected values for the first token, or [`None`] if the parser never consumes
+    /// tokens.
+    fn first_expects(&self) -> Option<Expects>;
+}
+
+impl<T, P: First<T> + ?Sized> First<T> for &mut P {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        (**self).first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        (**self).nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        (**self).first_expects()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T, P: First<T> + ?Sized> First<T> for Box<P> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        (**self).first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        (**self).nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        (**self).first_expects()
+    }
+}
+
+/// Merges two expected values of alternatives.
+pub(crate) fn merge_expects(this: Option<Expects>, other: Option<Expects>) -> Option<Expects> {
+    match (this, other) {
+        (Some(this), Some(other)) => Some(this.merge(other)),
+        (this, other) => this.or(other),
+    }
+}
@@ -0,0 +1,455 @@
+//! Generates random inputs accepted by parsers.
+//!
+//! Parsers implementing [`Generate`] can produce valid inputs for themselves, which is useful for
+//! fuzzing and property testing of code depending on grammars. Alternatives of [`choice`] are
+//! picked at random, repetitions like [`repeat`] pick the number of items within their ranges,
+//! and recursions by [`call!`] are limited by [`max_depth`].
+//!
+//! Since parsers try alternatives in order and repeat greedily, a few generated inputs may still
+//! be rejected by grammars which are ambiguous in this way.
+//!
+//! Conditions on outputs are not taken into account: [`satisfy`] and [`try_map`] generate inputs
+//! of the inner parsers as is, so inputs they reject can be generated. Restrict tokens by sets of
+//! [`one_of`] instead where possible.
+//!
+//! # Examples
+//! ```
+//! # futures::executor::block_on(async {
+//! use rand_core::SeedableRng;
+//! use rand_pcg::Pcg32;
+//! use somen::call;
+//! use somen::parser::generate::{Generate, Generator};
+//! use somen::prelude::*;
+//! use somen::stream::{Input, SliceStream};
+//!
+//! fn expr<'a, I>() -> impl Parser<I, Output = ()> + Generate<char> + 'a
+//! where
+//!     I: Input<Ok = char> + 'a,
+//! {
+//!     term().sep_by(one_of("+-"), 1..).discard()
+//! }
+//!
+//! fn term<'a, I>() -> impl Parser<I, Output = ()> + Generate<char> + 'a
+//! where
+//!     I: Input<Ok = char> + 'a,
+//! {
+//!     choice((
+//!         one_of('0'..='9').discard(),
+//!         call!(generate expr).between(token('('), token(')')),
+//!     ))
+//! }
+//!
+//! let mut rng = Pcg32::seed_from_u64(42);
+//! for _ in 0..16 {
+//!     let input = Generator::new(&mut rng)
+//!         .max_depth(4)
+//!         .generate(&mut expr::<SliceStream<'_, char>>())
+//!         .unwrap();
+//!
+//!     let mut stream = stream::from_slice(&input);
+//!     assert_eq!(expr().complete().parse(&mut stream).await, Ok(()));
+//! }
+//! # });
+//! ```
+//!
+//! [`choice`]: crate::parser::choice
+//! [`satisfy`]: crate::parser::ParserExt::satisfy
+//! [`try_map`]: crate::parser::ParserExt::try_map
+//! [`one_of`]: crate::parser::one_of
+//! [`repeat`]: crate::parser::ParserExt::repeat
+//! [`call!`]: crate::call
+//! [`max_depth`]: Generator::max_depth
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Bound, Range, RangeBounds, RangeInclusive};
+use rand_core::RngCore;
+
+use crate::parser::iterable::IterableParser;
+use crate::parser::Parser;
+use crate::stream::Positioned;
+
+/// A trait for parsers which can generate inputs they accept.
+pub trait Generate<T> {
+    /// Pushes tokens accepted by the parser to `gen`.
+    ///
+    /// Tokens pushed before an error are removed by callers trying other alternatives.
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError>;
+
+    /// Returns the number of alternatives, each alternative of [`choice`] is picked at the same
+    /// probability.
+    ///
+    /// [`choice`]: crate::parser::choice
+    #[inline]
+    fn choices(&self) -> usize {
+        1
+    }
+}
+
+impl<T, G: Generate<T> + ?Sized> Generate<T> for &mut G {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        (**self).generate(gen)
+    }
+
+    #[inline]
+    fn choices(&self) -> usize {
+        (**self).choices()
+    }
+}
+
+impl<T, G: Generate<T> + ?Sized> Generate<T> for Box<G> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        (**self).generate(gen)
+    }
+
+    #[inline]
+    fn choices(&self) -> usize {
+        (**self).choices()
+    }
+}
+
+/// A [`Parser`] which can generate inputs, boxed by [`boxed_generate`].
+///
+/// [`boxed_generate`]: crate::parser::ParserExt::boxed_generate
+pub trait GenerateParser<I: Positioned + ?Sized>: Parser<I> + Generate<I::Ok> {}
+
+impl<P, I> GenerateParser<I> for P
+where
+    P: Parser<I> + Generate<I::Ok> + ?Sized,
+    I: Positioned + ?Sized,
+{
+}
+
+/// An [`IterableParser`] which can generate inputs, boxed by [`boxed_generate`].
+///
+/// [`boxed_generate`]: crate::parser::iterable::IterableParserExt::boxed_generate
+pub trait GenerateIterableParser<I: Positioned + ?Sized>:
+    IterableParser<I> + Generate<I::Ok>
+{
+}
+
+impl<P, I> GenerateIterableParser<I> for P
+where
+    P: IterableParser<I> + Generate<I::Ok> + ?Sized,
+    I: Positioned + ?Sized,
+{
+}
+
+/// The error type for [`Generate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GenerateError {
+    /// Nested [`lazy`] parsers exceeded [`max_depth`].
+    ///
+    /// [`lazy`]: crate::parser::lazy
+    /// [`max_depth`]: Generator::max_depth
+    DepthExceeded,
+    /// No tokens can be picked, like [`one_of`] with an empty set.
+    ///
+    /// [`one_of`]: crate::parser::one_of
+    NoCandidates,
+}
+
+impl fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DepthExceeded => f.write_str("exceeded the maximum depth."),
+            Self::NoCandidates => f.write_str("no tokens to generate."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+impl std::error::Error for GenerateError {}
+
+/// Generates inputs from parsers with a random number generator.
+pub struct Generator<'a, T> {
+    rng: &'a mut dyn RngCore,
+    output: Vec<T>,
+    depth: usize,
+    max_depth: usize,
+    max_repeat: usize,
+}
+
+impl<T: fmt::Debug> fmt::Debug for Generator<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Generator")
+            .field("output", &self.output)
+            .field("depth", &self.depth)
+            .field("max_depth", &self.max_depth)
+            .field("max_repeat", &self.max_repeat)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T> Generator<'a, T> {
+    /// Creates a new instance, the maximum depth is 16 and the maximum repetition is 4.
+    #[inline]
+    pub fn new(rng: &'a mut dyn RngCore) -> Self {
+        Self {
+            rng,
+            output: Vec::new(),
+            depth: 0,
+            max_depth: 16,
+            max_repeat: 4,
+        }
+    }
+
+    /// Sets the maximum number of nested [`lazy`] parsers, like recursions by [`call!`].
+    ///
+    /// [`lazy`]: crate::parser::lazy
+    /// [`call!`]: crate::call
+    #[inline]
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Sets the maximum number of extra items for repetitions without upper bounds.
+    #[inline]
+    pub fn max_repeat(mut self, count: usize) -> Self {
+        self.max_repeat = count;
+        self
+    }
+
+    /// Generates an input accepted by `parser`.
+    pub fn generate<G: Generate<T> + ?Sized>(
+        mut self,
+        parser: &mut G,
+    ) -> Result<Vec<T>, GenerateError> {
+        parser.generate(&mut self)?;
+        Ok(self.output)
+    }
+
+    /// Returns the random number generator.
+    #[inline]
+    pub fn rng(&mut self) -> &mut dyn RngCore {
+        self.rng
+    }
+
+    /// Returns a random number below `n`, which must be positive.
+    #[inline]
+    pub fn below(&mut self, n: usize) -> usize {
+        below(self.rng, n as u64) as usize
+    }
+
+    /// Pushes a token to the output.
+    #[inline]
+    pub fn push(&mut self, token: T) {
+        self.output.push(token);
+    }
+
+    /// Returns the number of generated tokens.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.output.len()
+    }
+
+    /// Returns `true` if no tokens are generated.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.output.is_empty()
+    }
+
+    /// Removes tokens generated after the length was `len`.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.output.truncate(len);
+    }
+
+    /// Generates `parser`, removes tokens pushed by it on errors.
+    pub fn attempt<G: Generate<T> + ?Sized>(
+        &mut self,
+        parser: &mut G,
+    ) -> Result<(), GenerateError> {
+        let len = self.len();
+        parser.generate(self).inspect_err(|_| self.truncate(len))
+    }
+
+    /// Runs `f` one level deeper, fails if the depth exceeds the maximum.
+    pub fn nested<F>(&mut self, f: F) -> Result<(), GenerateError>
+    where
+        F: FnOnce(&mut Self) -> Result<(), GenerateError>,
+    {
+        if self.depth >= self.max_depth {
+            return Err(GenerateError::DepthExceeded);
+        }
+        self.depth += 1;
+        let res = f(self);
+        self.depth -= 1;
+        res
+    }
+
+    /// Generates items by `f` for a number of times picked from `range`, returns the number.
+    ///
+    /// `f` is called with the index of the item. Once the minimum number of items is generated,
+    /// a failed item is removed and the repetition stops there.
+    pub fn repeat<R, F>(&mut self, range: &R, mut f: F) -> Result<usize, GenerateError>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&mut Self, usize) -> Result<(), GenerateError>,
+    {
+        let min = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let max = match range.end_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_sub(1).ok_or(GenerateError::NoCandidates)?,
+            Bound::Unbounded => min.saturating_add(self.max_repeat),
+        };
+        if max < min {
+            return Err(GenerateError::NoCandidates);
+        }
+        let count = min + self.below((max - min).saturating_add(1));
+        for i in 0..count {
+            let len = self.len();
+            if let Err(err) = f(self, i) {
+                self.truncate(len);
+                return if i >= min { Ok(i) } else { Err(err) };
+            }
+        }
+        Ok(count)
+    }
+}
+
+impl<T> Extend<T> for Generator<'_, T> {
+    #[inline]
+    fn extend<It: IntoIterator<Item = T>>(&mut self, iter: It) {
+        self.output.extend(iter);
+    }
+}
+
+/// A trait for sets of tokens which can be sampled randomly, used by [`one_of`].
+///
+/// [`one_of`]: crate::parser::one_of
+pub trait Sample<T> {
+    /// Picks a token from the set, returns [`None`] if the set is empty.
+    fn sample(&self, rng: &mut dyn RngCore) -> Option<T>;
+}
+
+impl<T, S: Sample<T> + ?Sized> Sample<T> for &S {
+    #[inline]
+    fn sample(&self, rng: &mut dyn RngCore) -> Option<T> {
+        (**self).sample(rng)
+    }
+}
+
+impl<T: From<char>> Sample<T> for str {
+    fn sample(&self, rng: &mut dyn RngCore) -> Option<T> {
+        let len = self.chars().count();
+        if len == 0 {
+            return None;
+        }
+        self.chars()
+            .nth(below(rng, len as u64) as usize)
+            .map(T::from)
+    }
+}
+
+impl<T: From<char>> Sample<T> for String {
+    #[inline]
+    fn sample(&self, rng: &mut dyn RngCore) -> Option<T> {
+        self.as_str().sample(rng)
+    }
+}
+
+impl<T, U: Clone + Into<T>> Sample<T> for [U] {
+    fn sample(&self, rng: &mut dyn RngCore) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self[below(rng, self.len() as u64) as usize].clone().into())
+    }
+}
+
+impl<T, U: Clone + Into<T>, const N: usize> Sample<T> for [U; N] {
+    #[inline]
+    fn sample(&self, rng: &mut dyn RngCore) -> Option<T> {
+        self.as_slice().sample(rng)
+    }
+}
+
+impl<T, U: Clone + Into<T>> Sample<T> for Vec<U> {
+    #[inline]
+    fn sample(&self, rng: &mut dyn RngCore) -> Option<T> {
+        self.as_slice().sample(rng)
+    }
+}
+
+impl<T: From<char>> Sample<T> for RangeInclusive<char> {
+    fn sample(&self, rng: &mut dyn RngCore) -> Option<T> {
+        const SURROGATES: RangeInclusive<u32> = 0xD800..=0xDFFF;
+        let (start, end) = (*self.start() as u32, *self.end() as u32);
+        if start > end {
+            return None;
+        }
+        // Both ends are valid characters, so surrogates are contained entirely or not at all.
+        let skip = start < *SURROGATES.start() && end > *SURROGATES.end();
+        let gap = if skip {
+            SURROGATES.end() - SURROGATES.start() + 1
+        } else {
+            0
+        };
+        let mut c = start + below(rng, u64::from(end - start - gap) + 1) as u32;
+        if skip && c >= *SURROGATES.start() {
+            c += gap;
+        }
+        char::from_u32(c).map(T::from)
+    }
+}
+
+impl<T: From<char>> Sample<T> for Range<char> {
+    fn sample(&self, rng: &mut dyn RngCore) -> Option<T> {
+        let end = match self.end as u32 {
+            0xE000 => '\u{D7FF}',
+            end => char::from_u32(end.checked_sub(1)?)?,
+        };
+        (self.start..=end).sample(rng)
+    }
+}
+
+macro_rules! sample_impl_int {
+    ($($t:ty)*) => {
+        $(
+            impl<T: From<$t>> Sample<T> for RangeInclusive<$t> {
+                fn sample(&self, rng: &mut dyn RngCore) -> Option<T> {
+                    let (start, end) = (*self.start(), *self.end());
+                    if start > end {
+                        return None;
+                    }
+                    // The span doesn't fit in `u64` only for the full range of 64-bit integers.
+                    let span = (end as i128 - start as i128) as u128 + 1;
+                    let offset = match u64::try_from(span) {
+                        Ok(span) => below(rng, span),
+                        Err(_) => rng.next_u64(),
+                    };
+                    Some(T::from((start as i128 + offset as i128) as $t))
+                }
+            }
+
+            impl<T: From<$t>> Sample<T> for Range<$t> {
+                #[inline]
+                fn sample(&self, rng: &mut dyn RngCore) -> Option<T> {
+                    if self.start >= self.end {
+                        return None;
+                    }
+                    (self.start..=self.end - 1).sample(rng)
+                }
+            }
+        )*
+    };
+}
+
+sample_impl_int! { u8 u16 u32 u64 usize i8 i16 i32 i64 isize }
+
+/// Returns a random number below `n`, which must be positive.
+#[inline]
+fn below(rng: &mut dyn RngCore, n: u64) -> u64 {
+    rng.next_u64() % n
+}
@@ -0,0 +1,663 @@
+//! Describes the structure of parsers as grammars.
+//!
+//! Parsers implementing [`Describe`] can be printed as EBNF or ABNF, named rules are made of
+//! parsers wrapped by [`expect`].
+//!
+//! # Examples
+//! ```
+//! # futures::executor::block_on(async {
+//! use somen::prelude::*;
+//! use somen::parser::grammar::Describe;
+//!
+//! let mut parser = one_of("01")
+//!     .expect("bit")
+//!     .repeat(1..)
+//!     .discard()
+//!     .expect("bits")
+//!     .sep_by(token(','), ..)
+//!     .discard()
+//!     .between(token('['), token(']'));
+//!
+//! let mut stream = stream::from_iter("[10,0]".chars()).buffered_rewind();
+//! assert_eq!(parser.parse(&mut stream).await, Ok(()));
+//!
+//! assert_eq!(
+//!     parser.describe().to_ebnf("list"),
+//!     r#"list = "[", [ bits, { ",", bits } ], "]" ;
+//! bits = bit, { bit } ;
+//! bit = "0" | "1" ;
+//! "#,
+//! );
+//! assert_eq!(
+//!     parser.describe().to_abnf("list"),
+//!     r#"list = "[" [ bits *( "," bits ) ] "]"
+//! bits = 1*bit
+//! bit = "0" / "1"
+//! "#,
+//! );
+//! # });
+//! ```
+//!
+//! Recursive parsers called by [`call!`] with a leading `describe` are described too, the
+//! recursions are written as references to rules enclosing them.
+//! ```
+//! # futures::executor::block_on(async {
+//! use somen::{call, prelude::*};
+//! use somen::parser::grammar::Describe;
+//!
+//! fn value<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = usize> + Describe + 'a {
+//!     call!(describe value)
+//!         .sep_by(token(','), ..)
+//!         .count()
+//!         .between(token('['), token(']'))
+//!         .or(one_of("01").map(|_| 0))
+//!         .expect("value")
+//! }
+//!
+//! let mut parser = value();
+//! let mut stream = stream::from_iter("[0,[1],[]]".chars()).buffered_rewind();
+//! assert_eq!(parser.parse(&mut stream).await, Ok(3));
+//!
+//! assert_eq!(
+//!     parser.describe().to_ebnf("document"),
+//!     r#"document = value ;
+//! value = "[", [ value, { ",", value } ], "]" | "0" | "1" ;
+//! "#,
+//! );
+//! # });
+//! ```
+//!
+//! [`expect`]: crate::parser::ParserExt::expect
+//! [`call!`]: crate::call
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::ops::{Bound, RangeBounds};
+
+use crate::error::Expects;
+use crate::parser::iterable::IterableParser;
+use crate::parser::Parser;
+use crate::stream::Positioned;
+
+/// The structure of a parser.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Grammar {
+    /// Consumes no tokens.
+    Empty,
+    /// Any token.
+    Any,
+    /// The end of the input.
+    End,
+    /// A literal token, or a literal sequence of tokens.
+    Literal(String),
+    /// Tokens described in a natural language, like an expected value of [`Expects`].
+    Special(Cow<'static, str>),
+    /// Parses grammars in order.
+    Sequence(Vec<Grammar>),
+    /// Parses one of grammars, the first one is tried first.
+    Choice(Vec<Grammar>),
+    /// Parses the grammar from `min` to `max` (inclusive) times.
+    Repeat {
+        inner: Box<Grammar>,
+        min: usize,
+        max: Option<usize>,
+    },
+    /// Succeeds if the grammar follows, without consuming tokens.
+    Lookahead(Box<Grammar>),
+    /// Succeeds if the grammar doesn't follow, without consuming tokens.
+    NegativeLookahead(Box<Grammar>),
+    /// A named rule.
+    Rule { name: String, body: Box<Grammar> },
+    /// A reference to a named rule enclosing it, made by recursive rules.
+    Ref(String),
+    /// Unknown structure, like parsers produced at the time of parsing.
+    Opaque,
+}
+
+/// A trait for parsers which can describe their structure.
+pub trait Describe {
+    /// Returns the structure of the parser.
+    #[inline]
+    fn describe(&self) -> Grammar {
+        Describer::new().describe(self)
+    }
+
+    /// Returns the structure of the parser, inner parsers are described with `describer`.
+    fn describe_with(&self, describer: &mut Describer) -> Grammar;
+}
+
+impl<D: Describe + ?Sized> Describe for &D {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        (**self).describe_with(describer)
+    }
+}
+
+impl<D: Describe + ?Sized> Describe for &mut D {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        (**self).describe_with(describer)
+    }
+}
+
+impl<D: Describe + ?Sized> Describe for Box<D> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        (**self).describe_with(describer)
+    }
+}
+
+/// A [`Parser`] which can describe its structure, boxed by [`boxed_describe`].
+///
+/// [`boxed_describe`]: crate::parser::ParserExt::boxed_describe
+pub trait DescribeParser<I: Positioned + ?Sized>: Parser<I> + Describe {}
+
+impl<P, I> DescribeParser<I> for P
+where
+    P: Parser<I> + Describe + ?Sized,
+    I: Positioned + ?Sized,
+{
+}
+
+/// An [`IterableParser`] which can describe its structure, boxed by [`boxed_describe`].
+///
+/// [`boxed_describe`]: crate::parser::iterable::IterableParserExt::boxed_describe
+pub trait DescribeIterableParser<I: Positioned + ?Sized>: IterableParser<I> + Describe {}
+
+impl<P, I> DescribeIterableParser<I> for P
+where
+    P: IterableParser<I> + Describe + ?Sized,
+    I: Positioned + ?Sized,
+{
+}
+
+/// Describes parsers, keeping track of rules to describe recursions.
+///
+/// A rule is described as [`Grammar::Ref`] inside itself, so recursions by [`call!`] through a
+/// parser wrapped by [`expect`] are finite. Other recursions are cut off by the maximum depth.
+///
+/// [`call!`]: crate::call
+/// [`expect`]: crate::parser::ParserExt::expect
+#[derive(Clone, Debug)]
+pub struct Describer {
+    rules: Vec<String>,
+    depth: usize,
+    max_depth: usize,
+}
+
+impl Default for Describer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Describer {
+    /// Creates a new instance, the maximum depth is 4.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            depth: 0,
+            max_depth: 4,
+        }
+    }
+
+    /// Sets the maximum number of nested [`lazy`] parsers without rules between them, nested
+    /// ones are described as [`Grammar::Opaque`].
+    ///
+    /// [`lazy`]: crate::parser::lazy
+    #[inline]
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Returns the structure of `parser`.
+    #[inline]
+    pub fn describe<D: Describe + ?Sized>(mut self, parser: &D) -> Grammar {
+        parser.describe_with(&mut self)
+    }
+
+    /// Describes the rule `name` by `f`, returns a reference if the rule is being described.
+    pub fn rule<F>(&mut self, name: String, f: F) -> Grammar
+    where
+        F: FnOnce(&mut Self) -> Grammar,
+    {
+        if self.rules.contains(&name) {
+            return Grammar::Ref(name);
+        }
+        self.rules.push(name);
+        let depth = core::mem::replace(&mut self.depth, 0);
+        let body = f(self);
+        self.depth = depth;
+        let name = self.rules.pop().unwrap();
+        Grammar::Rule {
+            name,
+            body: Box::new(body),
+        }
+    }
+
+    /// Runs `f` one level deeper, returns [`Grammar::Opaque`] if the depth exceeds the maximum.
+    pub fn nested<F>(&mut self, f: F) -> Grammar
+    where
+        F: FnOnce(&mut Self) -> Grammar,
+    {
+        if self.depth >= self.max_depth {
+            return Grammar::Opaque;
+        }
+        self.depth += 1;
+        let res = f(self);
+        self.depth -= 1;
+        res
+    }
+}
+
+impl Grammar {
+    /// Creates a sequence, nested sequences are flattened and empty grammars are removed.
+    pub fn sequence<T: IntoIterator<Item = Grammar>>(iter: T) -> Self {
+        let mut items = Vec::new();
+        for item in iter {
+            match item {
+                Self::Empty => {}
+                Self::Sequence(inner) => items.extend(inner),
+                item => items.push(item),
+            }
+        }
+        match items.len() {
+            0 => Self::Empty,
+            1 => items.pop().unwrap(),
+            _ => Self::Sequence(items),
+        }
+    }
+
+    /// Creates a choice, nested choices are flattened and empty grammars make the choice
+    /// optional.
+    pub fn choice<T: IntoIterator<Item = Grammar>>(iter: T) -> Self {
+        let mut items = Vec::new();
+        let mut optional = false;
+        for item in iter {
+            match item {
+                Self::Empty => optional = true,
+                Self::Choice(inner) => items.extend(inner),
+                Self::Repeat {
+                    inner,
+                    min: 0,
+                    max: Some(1),
+                } => {
+                    optional = true;
+                    items.push(*inner);
+                }
+                item => items.push(item),
+            }
+        }
+        let choice = match items.len() {
+            0 => Self::Empty,
+            1 => items.pop().unwrap(),
+            _ => Self::Choice(items),
+        };
+        if optional {
+            Self::repeat(choice, ..=1)
+        } else {
+            choice
+        }
+    }
+
+    /// Creates a repetition, the number of times is specified by `range`.
+    pub fn repeat<R: RangeBounds<usize>>(inner: Grammar, range: R) -> Self {
+        let (min, max) = bounds(&range);
+        Self::repeat_between(inner, min, max)
+    }
+
+    /// Creates a repetition separated by `sep`, the number of items is specified by `range`.
+    ///
+    /// If `trailing` is `true`, a trailing separator is allowed.
+    pub fn separated<R: RangeBounds<usize>>(
+        inner: Grammar,
+        sep: Grammar,
+        range: R,
+        trailing: bool,
+    ) -> Self {
+        let (min, max) = bounds(&range);
+        if max == Some(0) {
+            return Self::Empty;
+        }
+        let rest = Self::repeat_between(
+            Self::sequence([sep.clone(), inner.clone()]),
+            min.saturating_sub(1),
+            max.map(|max| max - 1),
+        );
+        let mut items = Self::sequence([inner, rest]);
+        if trailing {
+            items = Self::sequence([items, Self::repeat(sep, ..=1)]);
+        }
+        if min == 0 {
+            Self::repeat(items, ..=1)
+        } else {
+            items
+        }
+    }
+
+    /// Creates a grammar from expected values, as a choice of literals.
+    ///
+    /// Values enclosed in `<` and `>` like `<set>` are treated as special sequences.
+    pub fn from_expects(expects: Expects) -> Self {
+        Self::choice(expects.into_iter().map(|exp| {
+            if exp.len() > 1 && exp.starts_with('<') && exp.ends_with('>') {
+                Self::Special(Cow::Owned(exp[1..exp.len() - 1].to_string()))
+            } else {
+                Self::Literal(exp.into_owned())
+            }
+        }))
+    }
+
+    /// Formats the grammar as ISO EBNF productions, the first one is named `name`.
+    ///
+    /// Literals which can't be quoted, like ones containing control characters, are written as
+    /// special sequences of the code points.
+    ///
+    /// # Examples
+    /// ```
+    /// use somen::parser::grammar::Grammar;
+    ///
+    /// let grammar = Grammar::choice([
+    ///     Grammar::Literal(String::from("\n")),
+    ///     Grammar::Literal(String::from("'\"")),
+    ///     Grammar::Literal(String::from("\"")),
+    /// ]);
+    /// assert_eq!(
+    ///     grammar.to_ebnf("quote"),
+    ///     "quote = ? U+000A ? | ? U+0027 U+0022 ? | '\"' ;\n",
+    /// );
+    /// ```
+    pub fn to_ebnf(&self, name: &str) -> String {
+        self.productions(name, Notation::Ebnf)
+    }
+
+    /// Formats the grammar as ABNF rules, the first one is named `name`.
+    pub fn to_abnf(&self, name: &str) -> String {
+        self.productions(name, Notation::Abnf)
+    }
+
+    fn repeat_between(inner: Grammar, min: usize, max: Option<usize>) -> Self {
+        match (inner, max) {
+            (Self::Empty, _) | (_, Some(0)) => Self::Empty,
+            (inner, Some(1)) if min == 1 => inner,
+            // Repeating an optional grammar is the same as repeating the grammar itself.
+            (
+                Self::Repeat {
+                    inner,
+                    min: 0,
+                    max: Some(1),
+                },
+                max,
+            ) if min == 0 => Self::Repeat { inner, min, max },
+            (inner, max) => Self::Repeat {
+                inner: Box::new(inner),
+                min,
+                max,
+            },
+        }
+    }
+
+    fn productions(&self, name: &str, notation: Notation) -> String {
+        let mut rules = Vec::from([(name, self)]);
+        let mut out = String::new();
+        let mut i = 0;
+        while let Some(&(name, body)) = rules.get(i) {
+            i += 1;
+            // Rules with unknown structure are only referred.
+            if i > 1 && *body == Self::Opaque {
+                continue;
+            }
+            out.push_str(&notation.name(name));
+            out.push_str(" = ");
+            body.write(&mut out, notation, 0, &mut rules);
+            out.push_str(match notation {
+                Notation::Ebnf | Notation::Prose => " ;\n",
+                Notation::Abnf => "\n",
+            });
+        }
+        out
+    }
+
+    // `prec` is 0 for alternatives, 1 for concatenated items and 2 for repeated items.
+    fn write<'a>(
+        &'a self,
+        out: &mut String,
+        notation: Notation,
+        prec: u8,
+        rules: &mut Vec<(&'a str, &'a Grammar)>,
+    ) {
+        match self {
+            Self::Empty => out.push_str("\"\""),
+            Self::Any => notation.special(out, "any token"),
+            Self::End => notation.special(out, "end of input"),
+            Self::Literal(lit) => notation.literal(out, lit),
+            Self::Special(text) => notation.special(out, text),
+            Self::Opaque => notation.special(out, "..."),
+            Self::Ref(name) => out.push_str(&notation.name(name)),
+            Self::Sequence(items) => {
+                let sep = match notation {
+                    Notation::Ebnf | Notation::Prose => ", ",
+                    Notation::Abnf => " ",
+                };
+                group(out, prec > 1, |out| {
+                    for (i, item) in items.iter().enumerate() {
+                        if i > 0 {
+                            out.push_str(sep);
+                        }
+                        item.write(out, notation, 1, rules);
+                    }
+                });
+            }
+            Self::Choice(items) => {
+                let sep = match notation {
+                    Notation::Ebnf | Notation::Prose => " | ",
+                    Notation::Abnf => " / ",
+                };
+                group(out, prec > 0, |out| {
+                    for (i, item) in items.iter().enumerate() {
+                        if i > 0 {
+                            out.push_str(sep);
+                        }
+                        item.write(out, notation, 1, rules);
+                    }
+                });
+            }
+            Self::Repeat { inner, min, max } => match notation {
+                Notation::Ebnf | Notation::Prose => {
+                    write_ebnf_repeat(out, notation, inner, *min, *max, prec, rules)
+                }
+                Notation::Abnf => {
+                    if (*min, *max) == (0, Some(1)) {
+                        out.push_str("[ ");
+                        inner.write(out, notation, 0, rules);
+                        out.push_str(" ]");
+                    } else {
+                        group(out, prec > 1, |out| {
+                            match (min, max) {
+                                (min, Some(max)) if min == max => write!(out, "{}", min).unwrap(),
+                                (min, max) => {
+                                    if *min > 0 {
+                                        write!(out, "{}", min).unwrap();
+                                    }
+                                    out.push('*');
+                                    if let Some(max) = max {
+                                        write!(out, "{}", max).unwrap();
+                                    }
+                                }
+                            }
+                            inner.write(out, notation, 2, rules);
+                        });
+                    }
+                }
+            },
+            Self::Lookahead(inner) | Self::NegativeLookahead(inner) => {
+                let mut text = String::from(match self {
+                    Self::Lookahead(_) => "followed by ",
+                    _ => "not followed by ",
+                });
+                inner.write(&mut text, Notation::Prose, 0, rules);
+                notation.special(out, &text);
+            }
+            Self::Rule { name, body } => {
+                if !rules.iter().any(|(n, _)| n == name) {
+                    rules.push((name, body));
+                }
+                out.push_str(&notation.name(name));
+            }
+        }
+    }
+}
+
+fn write_ebnf_repeat<'a>(
+    out: &mut String,
+    notation: Notation,
+    inner: &'a Grammar,
+    min: usize,
+    max: Option<usize>,
+    prec: u8,
+    rules: &mut Vec<(&'a str, &'a Grammar)>,
+) {
+    let write_times = |out: &mut String, n: usize, rules: &mut Vec<_>| {
+        if n > 1 {
+            write!(out, "{} * ", n).unwrap();
+            inner.write(out, notation, 2, rules);
+        } else {
+            inner.write(out, notation, prec.min(1), rules);
+        }
+    };
+    match (min, max) {
+        (0, Some(1)) => {
+            out.push_str("[ ");
+            inner.write(out, notation, 0, rules);
+            out.push_str(" ]");
+        }
+        (0, None) => {
+            out.push_str("{ ");
+            inner.write(out, notation, 0, rules);
+            out.push_str(" }");
+        }
+        (min, Some(max)) if min == max => {
+            group(out, prec > 1 && min > 1, |out| write_times(out, min, rules))
+        }
+        (min, max) => group(out, prec > 1, |out| {
+            if min > 0 {
+                write_times(out, min, rules);
+                out.push_str(", ");
+            }
+            match max {
+                Some(max) => {
+                    if max - min > 1 {
+                        write!(out, "{} * ", max - min).unwrap();
+                    }
+                    out.push_str("[ ");
+                    inner.write(out, notation, 0, rules);
+                    out.push_str(" ]");
+                }
+                None => {
+                    out.push_str("{ ");
+                    inner.write(out, notation, 0, rules);
+                    out.push_str(" }");
+                }
+            }
+        }),
+    }
+}
+
+fn group<F: FnOnce(&mut String)>(out: &mut String, paren: bool, f: F) {
+    if paren {
+        out.push_str("( ");
+    }
+    f(out);
+    if paren {
+        out.push_str(" )");
+    }
+}
+
+fn bounds<R: RangeBounds<usize>>(range: &R) -> (usize, Option<usize>) {
+    let min = match range.start_bound() {
+        Bound::Included(i) => *i,
+        Bound::Excluded(i) => i + 1,
+        Bound::Unbounded => 0,
+    };
+    let max = match range.end_bound() {
+        Bound::Included(i) => Some(*i),
+        Bound::Excluded(i) => Some(i.saturating_sub(1)),
+        Bound::Unbounded => None,
+    };
+    (min, max)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Notation {
+    Ebnf,
+    Abnf,
+    // EBNF without delimiters of special sequences, used in special sequences.
+    Prose,
+}
+
+impl Notation {
+    fn name(self, name: &str) -> String {
+        let sep = match self {
+            Self::Ebnf | Self::Prose => '_',
+            Self::Abnf => '-',
+        };
+        let name = name
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(&sep.to_string());
+        match name.chars().next() {
+            Some(c) if c.is_ascii_alphabetic() => name,
+            _ => format!("rule{}{}", sep, name),
+        }
+    }
+
+    fn literal(self, out: &mut String, lit: &str) {
+        match self {
+            Self::Ebnf | Self::Prose
+                if lit.chars().any(char::is_control) || lit.contains('"') && lit.contains('\'') =>
+            {
+                let codes = lit
+                    .chars()
+                    .map(|c| format!("U+{:04X}", c as u32))
+                    .collect::<Vec<_>>();
+                self.special(out, &codes.join(" "))
+            }
+            Self::Ebnf | Self::Prose if lit.contains('"') => write!(out, "'{}'", lit).unwrap(),
+            Self::Ebnf | Self::Prose => write!(out, "\"{}\"", lit).unwrap(),
+            // Quoted strings are case-insensitive in ABNF, so `%s` is used for letters (RFC 7405).
+            Self::Abnf
+                if !lit.is_empty() && lit.chars().all(|c| (' '..='~').contains(&c) && c != '"') =>
+            {
+                if lit.chars().any(|c| c.is_ascii_alphabetic()) {
+                    out.push_str("%s");
+                }
+                write!(out, "\"{}\"", lit).unwrap()
+            }
+            Self::Abnf => {
+                out.push_str("%x");
+                for (i, c) in lit.chars().enumerate() {
+                    if i > 0 {
+                        out.push('.');
+                    }
+                    write!(out, "{:02X}", c as u32).unwrap();
+                }
+            }
+        }
+    }
+
+    fn special(self, out: &mut String, text: &str) {
+        match self {
+            Self::Ebnf => write!(out, "? {} ?", text).unwrap(),
+            Self::Abnf => write!(out, "<{}>", text).unwrap(),
+            Self::Prose => out.push_str(text),
+        }
+    }
+}
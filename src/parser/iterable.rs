@@ -23,6 +23,11 @@ use stream::IterableParserStream;
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
+#[cfg(feature = "generate")]
+use super::generate::{Generate, GenerateIterableParser};
+#[cfg(feature = "alloc")]
+use super::grammar::{Describe, DescribeIterableParser};
+
 /// A conventional function to produce a nested [`or`] parser from a tuple of iterable parsers.
 ///
 /// For example, `choice_iterable((a, b, c))` is equivalent to `a.or(b).or(c)`.
@@ -96,6 +101,32 @@ pub trait IterableParserExt<I: Positioned + ?Sized>: IterableParser<I> {
         assert_iterable_parser(Box::new(self))
     }
 
+    /// Wraps the parser into a [`Box`], keeping the ability to generate inputs.
+    #[cfg(feature = "generate")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "generate")))]
+    #[inline]
+    fn boxed_generate<'a>(
+        self,
+    ) -> Box<dyn GenerateIterableParser<I, Item = Self::Item, State = Self::State> + 'a>
+    where
+        Self: Sized + Generate<I::Ok> + 'a,
+    {
+        assert_iterable_parser(Box::new(self))
+    }
+
+    /// Wraps the parser into a [`Box`], keeping the ability to describe its structure.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn boxed_describe<'a>(
+        self,
+    ) -> Box<dyn DescribeIterableParser<I, Item = Self::Item, State = Self::State> + 'a>
+    where
+        Self: Sized + Describe + 'a,
+    {
+        assert_iterable_parser(Box::new(self))
+    }
+
     /// Merges [`State`] into the parser itself.
     ///
     /// [`State`]: IterableParser::State
@@ -505,7 +536,7 @@ pub trait IterableParserExt<I: Positioned + ?Sized>: IterableParser<I> {
 
 impl<P: IterableParser<I> + ?Sized, I: Positioned + ?Sized> IterableParserExt<I> for P {}
 
-impl<'a, P: IterableParser<I> + ?Sized, I: Positioned + ?Sized> IterableParser<I> for &'a mut P {
+impl<P: IterableParser<I> + ?Sized, I: Positioned + ?Sized> IterableParser<I> for &mut P {
     type Item = P::Item;
     type State = P::State;
 
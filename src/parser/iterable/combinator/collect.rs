@@ -3,7 +3,12 @@ use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::ready;
 
-use crate::error::{Error, PolledResult, Status};
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::utils::merge_errors;
 use crate::parser::Parser;
@@ -93,3 +98,36 @@ where
         }))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, E> Describe for Collect<P, E> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        self.inner.describe_with(describer)
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>, E> Generate<T> for Collect<P, E> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        self.inner.generate(gen)
+    }
+}
+
+impl<T, P: First<T>, E> First<T> for Collect<P, E> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.inner.first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        self.inner.first_expects()
+    }
+}
@@ -2,7 +2,12 @@ use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::ready;
 
-use crate::error::{Error, PolledResult, Status};
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::utils::merge_errors;
 use crate::parser::Parser;
@@ -76,3 +81,36 @@ where
         }))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe> Describe for Count<P> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        self.inner.describe_with(describer)
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>> Generate<T> for Count<P> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        self.inner.generate(gen)
+    }
+}
+
+impl<T, P: First<T>> First<T> for Count<P> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.inner.first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        self.inner.first_expects()
+    }
+}
@@ -2,6 +2,10 @@ use core::pin::Pin;
 use core::task::Context;
 
 use crate::error::{PolledResult, Status};
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::stream::Positioned;
 
@@ -61,3 +65,19 @@ where
             })
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe> Describe for Enumerate<P> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        self.inner.describe_with(describer)
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>> Generate<T> for Enumerate<P> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        self.inner.generate(gen)
+    }
+}
@@ -3,6 +3,8 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, PolledResult, Status};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::utils::merge_errors;
 use crate::stream::Positioned;
@@ -77,3 +79,11 @@ where
         }))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, F> Describe for Filter<P, F> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        self.inner.describe_with(describer)
+    }
+}
@@ -3,6 +3,9 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::{merge_expects, First};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::utils::{merge_errors, EitherState};
 use crate::parser::Parser;
@@ -194,3 +197,67 @@ where
         }))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, Q: Describe, F> Describe for Fold<P, Q, F> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::sequence([
+            self.init.describe_with(describer),
+            self.inner.describe_with(describer),
+        ])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, Q: Describe, F> Describe for TryFold<P, Q, F> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::sequence([
+            self.init.describe_with(describer),
+            self.inner.describe_with(describer),
+        ])
+    }
+}
+
+impl<T, P: First<T>, Q: First<T>, F> First<T> for Fold<P, Q, F> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.init.first(token) || (self.init.nullable() && self.inner.first(token))
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.init.nullable() && self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        if self.init.nullable() {
+            merge_expects(self.init.first_expects(), self.inner.first_expects())
+        } else {
+            self.init.first_expects()
+        }
+    }
+}
+
+impl<T, P: First<T>, Q: First<T>, F> First<T> for TryFold<P, Q, F> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.init.first(token) || (self.init.nullable() && self.inner.first(token))
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.init.nullable() && self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        if self.init.nullable() {
+            merge_expects(self.init.first_expects(), self.inner.first_expects())
+        } else {
+            self.init.first_expects()
+        }
+    }
+}
@@ -4,6 +4,8 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, PolledResult, Status};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::utils::merge_errors;
 use crate::parser::Parser;
@@ -152,8 +154,14 @@ impl<T, const N: usize> UninitBuffer<T, N> {
             MaybeUninit::uninit().assume_init()
         });
         let ptr = &mut buf as *mut _ as *mut [T; N];
-        let res = unsafe { ptr.read() };
-        core::mem::forget(buf);
-        res
+        unsafe { ptr.read() }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, const N: usize> Describe for Indices<P, N> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        self.inner.describe_with(describer)
     }
 }
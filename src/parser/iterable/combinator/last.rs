@@ -3,6 +3,10 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, PolledResult, Status};
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::utils::merge_errors;
 use crate::parser::Parser;
@@ -74,3 +78,19 @@ where
         }))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe> Describe for Last<P> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        self.inner.describe_with(describer)
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>> Generate<T> for Last<P> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        self.inner.generate(gen)
+    }
+}
@@ -3,6 +3,8 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, Expects, PolledResult, Status};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::utils::{merge_errors, EitherState};
 use crate::parser::Parser;
@@ -207,3 +209,25 @@ where
         }))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, Q: Describe, F> Describe for Scan<P, Q, F> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::sequence([
+            self.init.describe_with(describer),
+            self.inner.describe_with(describer),
+        ])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, Q: Describe, F> Describe for TryScan<P, Q, F> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::sequence([
+            self.init.describe_with(describer),
+            self.inner.describe_with(describer),
+        ])
+    }
+}
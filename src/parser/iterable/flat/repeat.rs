@@ -4,6 +4,10 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, PolledResult, Status};
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::utils::merge_errors;
 use crate::prelude::IterableParser;
 use crate::stream::Input;
@@ -141,3 +145,22 @@ where
         (start, end)
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, R: RangeBounds<usize>> Describe for FlatRepeat<P, R> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::repeat(
+            self.inner.describe_with(describer),
+            (self.range.start_bound(), self.range.end_bound()),
+        )
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>, R: RangeBounds<usize>> Generate<T> for FlatRepeat<P, R> {
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        gen.repeat(&self.range, |gen, _| self.inner.generate(gen))?;
+        Ok(())
+    }
+}
@@ -4,6 +4,10 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, PolledResult, Status};
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::utils::{merge_errors, EitherState};
 use crate::parser::Parser;
 use crate::prelude::IterableParser;
@@ -168,3 +172,35 @@ where
         (start, end)
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, Q: Describe, R: RangeBounds<usize>> Describe for FlatSepByEnd<P, Q, R> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::separated(
+            self.inner.describe_with(describer),
+            self.sep.describe_with(describer),
+            (self.range.start_bound(), self.range.end_bound()),
+            true,
+        )
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>, Q: Generate<T>, R: RangeBounds<usize>> Generate<T>
+    for FlatSepByEnd<P, Q, R>
+{
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        let count = gen.repeat(&self.range, |gen, i| {
+            if i > 0 {
+                self.sep.generate(gen)?;
+            }
+            self.inner.generate(gen)
+        })?;
+        // Adds a trailing separator sometimes.
+        if count > 0 && gen.below(2) == 0 {
+            gen.attempt(&mut self.sep)?;
+        }
+        Ok(())
+    }
+}
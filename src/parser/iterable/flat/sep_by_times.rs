@@ -3,6 +3,10 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, PolledResult, Status};
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::utils::{merge_errors, EitherState};
 use crate::parser::Parser;
@@ -103,3 +107,29 @@ where
         (self.count, Some(self.count))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, Q: Describe> Describe for FlatSepByTimes<P, Q> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::separated(
+            self.inner.describe_with(describer),
+            self.sep.describe_with(describer),
+            self.count..=self.count,
+            false,
+        )
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>, Q: Generate<T>> Generate<T> for FlatSepByTimes<P, Q> {
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        gen.repeat(&(self.count..=self.count), |gen, i| {
+            if i > 0 {
+                self.sep.generate(gen)?;
+            }
+            self.inner.generate(gen)
+        })?;
+        Ok(())
+    }
+}
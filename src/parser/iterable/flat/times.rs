@@ -3,6 +3,10 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, PolledResult, Status};
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::utils::merge_errors;
 use crate::prelude::{Positioned, IterableParser};
 
@@ -83,3 +87,21 @@ where
         (self.count, Some(self.count))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe> Describe for FlatTimes<P> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::repeat(self.inner.describe_with(describer), self.count..=self.count)
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>> Generate<T> for FlatTimes<P> {
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        gen.repeat(&(self.count..=self.count), |gen, _| {
+            self.inner.generate(gen)
+        })?;
+        Ok(())
+    }
+}
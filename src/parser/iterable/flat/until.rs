@@ -3,6 +3,10 @@ use core::task::{Context, Poll};
 use futures_core::ready;
 
 use crate::error::{Error, PolledResult, Status};
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::utils::{merge_errors, EitherState};
 use crate::parser::Parser;
 use crate::prelude::IterableParser;
@@ -109,3 +113,22 @@ where
         }))
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, Q: Describe> Describe for FlatUntil<P, Q> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::sequence([
+            Grammar::repeat(self.inner.describe_with(describer), ..),
+            self.end.describe_with(describer),
+        ])
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>, Q: Generate<T>> Generate<T> for FlatUntil<P, Q> {
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        gen.repeat(&(..), |gen, _| self.inner.generate(gen))?;
+        self.end.generate(gen)
+    }
+}
@@ -3,7 +3,12 @@ use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::ready;
 
-use crate::error::{PolledResult, Status};
+use crate::error::{Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::Parser;
 use crate::prelude::IterableParser;
 use crate::stream::Input;
@@ -113,3 +118,42 @@ where
         (start, end)
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, R: RangeBounds<usize>> Describe for Repeat<P, R> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::repeat(
+            self.inner.describe_with(describer),
+            (self.range.start_bound(), self.range.end_bound()),
+        )
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>, R: RangeBounds<usize>> Generate<T> for Repeat<P, R> {
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        gen.repeat(&self.range, |gen, _| self.inner.generate(gen))?;
+        Ok(())
+    }
+}
+
+impl<T, P: First<T>, R: RangeBounds<usize>> First<T> for Repeat<P, R> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.inner.first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        matches!(
+            self.range.start_bound(),
+            Bound::Unbounded | Bound::Included(0)
+        ) || self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        self.inner.first_expects()
+    }
+}
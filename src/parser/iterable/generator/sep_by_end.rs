@@ -3,7 +3,12 @@ use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::ready;
 
-use crate::error::{Error, PolledResult, Status};
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::utils::{merge_errors, EitherState};
 use crate::parser::Parser;
 use crate::prelude::IterableParser;
@@ -149,3 +154,53 @@ where
         (start, end)
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, Q: Describe, R: RangeBounds<usize>> Describe for SepByEnd<P, Q, R> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        Grammar::separated(
+            self.inner.describe_with(describer),
+            self.sep.describe_with(describer),
+            (self.range.start_bound(), self.range.end_bound()),
+            true,
+        )
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>, Q: Generate<T>, R: RangeBounds<usize>> Generate<T> for SepByEnd<P, Q, R> {
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        let count = gen.repeat(&self.range, |gen, i| {
+            if i > 0 {
+                self.sep.generate(gen)?;
+            }
+            self.inner.generate(gen)
+        })?;
+        // Adds a trailing separator sometimes.
+        if count > 0 && gen.below(2) == 0 {
+            gen.attempt(&mut self.sep)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T, P: First<T>, Q, R: RangeBounds<usize>> First<T> for SepByEnd<P, Q, R> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.inner.first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        matches!(
+            self.range.start_bound(),
+            Bound::Unbounded | Bound::Included(0)
+        ) || self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        self.inner.first_expects()
+    }
+}
@@ -0,0 +1,225 @@
+//! Parsers scanning contiguous inputs at once.
+//!
+//! Parsers here require inputs implementing [`Contiguous`] (e.g. [`SliceStream`]), compare or scan
+//! the remaining slice at once instead of polling tokens one by one, and return slices borrowed
+//! from the input.
+//!
+//! [`Contiguous`]: crate::stream::contiguous::Contiguous
+//! [`SliceStream`]: crate::stream::SliceStream
+mod span_of;
+mod tag;
+mod take;
+mod tokens;
+
+pub use span_of::SpanOf;
+pub use tag::{SliceTag, TagToken};
+pub use take::{SliceTake, SliceTakeUntil, SliceTakeWhile};
+pub use tokens::SliceTokens;
+
+use core::pin::Pin;
+
+use crate::error::{Error, Expects};
+use crate::parser::assert_parser;
+use crate::parser::atomic::Set;
+use crate::stream::contiguous::Contiguous;
+
+/// Parses a string, as bytes or characters.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::slice;
+///
+/// let mut stream = stream::from_slice(b"GET /");
+/// assert_eq!(slice::tag("GET").parse(&mut stream).await, Ok("GET"));
+/// assert!(slice::tag("POST").parse(&mut stream).await.is_err());
+///
+/// let input = "日本語".chars().collect::<Vec<_>>();
+/// let mut stream = stream::from_slice(&input);
+/// assert_eq!(slice::tag("日本").parse(&mut stream).await, Ok("日本"));
+/// assert_eq!(any().parse(&mut stream).await, Ok('語'));
+/// # });
+/// ```
+#[inline]
+pub fn tag<'a, I>(tag: &'static str) -> SliceTag<'a, I>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: TagToken + 'a,
+{
+    assert_parser(SliceTag::new(tag))
+}
+
+/// Parses a sequence of tokens, returns the slice of the input.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::slice;
+///
+/// let input = [1, 2, 3, 4];
+/// let mut stream = stream::from_slice(&input);
+/// assert_eq!(slice::tokens([1, 2]).parse(&mut stream).await, Ok(&input[..2]));
+/// assert_eq!(slice::tokens([3, 4]).parse(&mut stream).await, Ok(&input[2..]));
+/// # });
+/// ```
+#[inline]
+pub fn tokens<'a, I, B>(tokens: B) -> SliceTokens<'a, I, B>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: PartialEq + 'a,
+    B: AsRef<[I::Ok]>,
+{
+    assert_parser(SliceTokens::new(tokens))
+}
+
+/// Parses the longest sequence of tokens which the set contains, returns the slice of the input.
+///
+/// It may be empty. The set is checked with [`Set::prefix_len`] over the whole slice at once.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::slice;
+///
+/// let mut stream = stream::from_slice(b"  \tfoo");
+/// assert_eq!(slice::span_of(b" \t").parse(&mut stream).await, Ok(&b"  \t"[..]));
+/// assert_eq!(slice::span_of(b'a'..=b'z').parse(&mut stream).await, Ok(&b"foo"[..]));
+/// assert_eq!(slice::span_of(b'0'..=b'9').parse(&mut stream).await, Ok(&b""[..]));
+/// # });
+/// ```
+#[inline]
+pub fn span_of<'a, I, S>(set: S) -> SpanOf<'a, I, S>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: 'a,
+    S: Set<I::Ok>,
+{
+    assert_parser(SpanOf::new(set))
+}
+
+/// Parses the longest sequence of tokens matching the condition, returns the slice of the input.
+///
+/// It may be empty. See [`take_while`](crate::parser::take_while) for other inputs.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::slice;
+///
+/// let mut parser = slice::take_while(|b: &u8| b.is_ascii_digit());
+/// let mut stream = stream::from_slice(b"123abc");
+/// assert_eq!(parser.parse(&mut stream).await, Ok(&b"123"[..]));
+/// assert_eq!(parser.parse(&mut stream).await, Ok(&b""[..]));
+/// # });
+/// ```
+#[inline]
+pub fn take_while<'a, I, F>(cond: F) -> SliceTakeWhile<'a, I, F>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: 'a,
+    F: FnMut(&I::Ok) -> bool,
+{
+    assert_parser(SliceTakeWhile::new(cond, 0))
+}
+
+/// Parses the longest non-empty sequence of tokens matching the condition, returns the slice of
+/// the input.
+///
+/// On failure, the error spans the first token. Give it a name by [`expect`].
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::slice;
+///
+/// let mut parser = slice::take_while1(|b: &u8| b.is_ascii_digit()).expect("a digit");
+/// let mut stream = stream::from_slice(b"42x");
+/// assert_eq!(parser.parse(&mut stream).await, Ok(&b"42"[..]));
+///
+/// let err = parser.parse(&mut stream).await.unwrap_err();
+/// assert_eq!(err.to_string(), "expected a digit.");
+/// assert!(matches!(err, somen::error::ParseError::Parser(e) if e.position == (2..3)));
+/// # });
+/// ```
+///
+/// [`expect`]: crate::parser::ParserExt::expect
+#[inline]
+pub fn take_while1<'a, I, F>(cond: F) -> SliceTakeWhile<'a, I, F>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: 'a,
+    F: FnMut(&I::Ok) -> bool,
+{
+    assert_parser(SliceTakeWhile::new(cond, 1))
+}
+
+/// Parses tokens until the pattern appears, returns the slice of the input before the pattern.
+///
+/// The pattern is not consumed. Fails if the pattern never appears.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::slice;
+///
+/// let mut stream = stream::from_slice(b"a comment */ rest");
+/// assert_eq!(slice::take_until(b"*/").parse(&mut stream).await, Ok(&b"a comment "[..]));
+/// assert_eq!(slice::tag("*/").parse(&mut stream).await, Ok("*/"));
+/// assert!(slice::take_until(b"*/").parse(&mut stream).await.is_err());
+/// # });
+/// ```
+#[inline]
+pub fn take_until<'a, I, B>(pattern: B) -> SliceTakeUntil<'a, I, B>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: PartialEq + 'a,
+    B: AsRef<[I::Ok]>,
+{
+    assert_parser(SliceTakeUntil::new(pattern))
+}
+
+/// Parses `n` tokens, returns the slice of the input.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::slice;
+///
+/// let mut stream = stream::from_slice(b"abcde");
+/// assert_eq!(slice::take(3).parse(&mut stream).await, Ok(&b"abc"[..]));
+/// assert!(slice::take(3).parse(&mut stream).await.is_err());
+/// # });
+/// ```
+#[inline]
+pub fn take<'a, I>(n: usize) -> SliceTake<'a, I>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: 'a,
+{
+    assert_parser(SliceTake::new(n))
+}
+
+/// Consumes the input after `matched` tokens matched, as parsers reading tokens one by one do,
+/// and returns the error spanning the first token.
+fn mismatch<'a, I>(mut input: Pin<&mut I>, matched: usize, expects: Expects) -> Error<I::Locator>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: 'a,
+{
+    let start = input.position();
+    let consumed = (matched + 1).min(input.remaining().len());
+    input.as_mut().advance(consumed.min(1));
+    let next = input.position();
+    input.advance(consumed.saturating_sub(1));
+    Error {
+        expects,
+        position: start..next,
+    }
+}
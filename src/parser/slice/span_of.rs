@@ -0,0 +1,88 @@
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use crate::error::{Expects, PolledResult, Status};
+use crate::parser::atomic::Set;
+use crate::parser::first::First;
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::Parser;
+use crate::stream::contiguous::Contiguous;
+
+/// A parser for function [`span_of`].
+///
+/// [`span_of`]: crate::parser::slice::span_of
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpanOf<'a, I: ?Sized, S> {
+    set: S,
+    _phantom: PhantomData<&'a I>,
+}
+
+impl<I: ?Sized, S> SpanOf<'_, I, S> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(set: S) -> Self {
+        Self {
+            set,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, I, S> Parser<I> for SpanOf<'a, I, S>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: 'a,
+    S: Set<I::Ok>,
+{
+    type Output = &'a [I::Ok];
+    type State = ();
+
+    fn poll_parse(
+        &mut self,
+        input: Pin<&mut I>,
+        _cx: &mut Context<'_>,
+        _state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        let remaining = input.remaining();
+        let len = self.set.prefix_len(remaining);
+        input.advance(len);
+        Poll::Ready(Ok(Status::Success(&remaining[..len], None)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, I, S> Describe for SpanOf<'a, I, S>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: 'a,
+    S: Set<I::Ok>,
+{
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::repeat(Grammar::from_expects(self.set.to_expects()), ..)
+    }
+}
+
+impl<'a, I, S> First<I::Ok> for SpanOf<'a, I, S>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: 'a,
+    S: Set<I::Ok>,
+{
+    #[inline]
+    fn first(&mut self, token: &I::Ok) -> bool {
+        self.set.contains(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(self.set.to_expects())
+    }
+}
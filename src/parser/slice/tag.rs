@@ -0,0 +1,132 @@
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use super::mismatch;
+use crate::error::{Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::Parser;
+use crate::stream::contiguous::Contiguous;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
+
+/// Tokens which can be compared with strings by [`SliceTag`].
+pub trait TagToken: Sized {
+    /// Returns the number of leading tokens matching `tag`, and whether the whole `tag` matched.
+    fn match_tag(tokens: &[Self], tag: &str) -> (usize, bool);
+
+    /// Returns `true` if `tag` starts with the token.
+    fn starts_tag(&self, tag: &str) -> bool;
+}
+
+impl TagToken for u8 {
+    #[inline]
+    fn match_tag(tokens: &[Self], tag: &str) -> (usize, bool) {
+        let tag = tag.as_bytes();
+        if tokens.starts_with(tag) {
+            (tag.len(), true)
+        } else {
+            let matched = tokens.iter().zip(tag).take_while(|(i, t)| i == t).count();
+            (matched, false)
+        }
+    }
+
+    #[inline]
+    fn starts_tag(&self, tag: &str) -> bool {
+        tag.as_bytes().first() == Some(self)
+    }
+}
+
+impl TagToken for char {
+    #[inline]
+    fn match_tag(tokens: &[Self], tag: &str) -> (usize, bool) {
+        let mut matched = 0;
+        for c in tag.chars() {
+            if tokens.get(matched) != Some(&c) {
+                return (matched, false);
+            }
+            matched += 1;
+        }
+        (matched, true)
+    }
+
+    #[inline]
+    fn starts_tag(&self, tag: &str) -> bool {
+        tag.starts_with(*self)
+    }
+}
+
+/// A parser for function [`tag`].
+///
+/// [`tag`]: crate::parser::slice::tag
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SliceTag<'a, I: ?Sized> {
+    tag: &'static str,
+    _phantom: PhantomData<&'a I>,
+}
+
+impl<I: ?Sized> SliceTag<'_, I> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(tag: &'static str) -> Self {
+        Self {
+            tag,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, I> Parser<I> for SliceTag<'a, I>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: TagToken + 'a,
+{
+    type Output = &'static str;
+    type State = ();
+
+    fn poll_parse(
+        &mut self,
+        input: Pin<&mut I>,
+        _cx: &mut Context<'_>,
+        _state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        let (matched, complete) = I::Ok::match_tag(input.remaining(), self.tag);
+        Poll::Ready(Ok(if complete {
+            input.advance(matched);
+            Status::Success(self.tag, None)
+        } else {
+            Status::Failure(mismatch(input, matched, Expects::from(self.tag)), false)
+        }))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized> Describe for SliceTag<'_, I> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Literal(self.tag.to_string())
+    }
+}
+
+impl<'a, I> First<I::Ok> for SliceTag<'a, I>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: TagToken + 'a,
+{
+    #[inline]
+    fn first(&mut self, token: &I::Ok) -> bool {
+        token.starts_tag(self.tag)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.tag.is_empty()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from(self.tag))
+    }
+}
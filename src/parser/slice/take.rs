@@ -0,0 +1,278 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use super::mismatch;
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::Parser;
+use crate::stream::contiguous::Contiguous;
+
+/// A parser for functions [`take_while`] and [`take_while1`].
+///
+/// [`take_while`]: crate::parser::slice::take_while
+/// [`take_while1`]: crate::parser::slice::take_while1
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SliceTakeWhile<'a, I: ?Sized, F> {
+    cond: F,
+    min: usize,
+    _phantom: PhantomData<&'a I>,
+}
+
+impl<I: ?Sized, F> SliceTakeWhile<'_, I, F> {
+    /// Creates a new instance, which requires at least `min` tokens.
+    #[inline]
+    pub fn new(cond: F, min: usize) -> Self {
+        Self {
+            cond,
+            min,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, I, F> Parser<I> for SliceTakeWhile<'a, I, F>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: 'a,
+    F: FnMut(&I::Ok) -> bool,
+{
+    type Output = &'a [I::Ok];
+    type State = ();
+
+    fn poll_parse(
+        &mut self,
+        input: Pin<&mut I>,
+        _cx: &mut Context<'_>,
+        _state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        let remaining = input.remaining();
+        let len = remaining
+            .iter()
+            .position(|token| !(self.cond)(token))
+            .unwrap_or(remaining.len());
+        Poll::Ready(Ok(if len < self.min {
+            Status::Failure(mismatch(input, len, Expects::from("<cond>")), false)
+        } else {
+            input.advance(len);
+            Status::Success(&remaining[..len], None)
+        }))
+    }
+}
+
+/// A parser for function [`take_until`].
+///
+/// [`take_until`]: crate::parser::slice::take_until
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SliceTakeUntil<'a, I: ?Sized, B> {
+    pattern: B,
+    _phantom: PhantomData<&'a I>,
+}
+
+impl<I: ?Sized, B> SliceTakeUntil<'_, I, B> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(pattern: B) -> Self {
+        Self {
+            pattern,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, I, B> Parser<I> for SliceTakeUntil<'a, I, B>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: PartialEq + 'a,
+    B: AsRef<[I::Ok]>,
+{
+    type Output = &'a [I::Ok];
+    type State = ();
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        _cx: &mut Context<'_>,
+        _state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        let remaining = input.remaining();
+        let pattern = self.pattern.as_ref();
+        let found = match pattern.split_first() {
+            None => Some(0),
+            // Finds candidates by the first token, then compares the rest.
+            Some((first, rest)) => remaining
+                .iter()
+                .enumerate()
+                .filter(|(_, token)| *token == first)
+                .map(|(i, _)| i)
+                .find(|&i| remaining[i + 1..].starts_with(rest)),
+        };
+        Poll::Ready(Ok(match found {
+            Some(len) => {
+                input.advance(len);
+                Status::Success(&remaining[..len], None)
+            }
+            None => {
+                let start = input.position();
+                input.as_mut().advance(remaining.len());
+                Status::Failure(
+                    Error {
+                        expects: Expects::from("<tokens>"),
+                        position: start..input.position(),
+                    },
+                    false,
+                )
+            }
+        }))
+    }
+}
+
+/// A parser for function [`take`].
+///
+/// [`take`]: crate::parser::slice::take
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SliceTake<'a, I: ?Sized> {
+    n: usize,
+    _phantom: PhantomData<&'a I>,
+}
+
+impl<I: ?Sized> SliceTake<'_, I> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, I> Parser<I> for SliceTake<'a, I>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: 'a,
+{
+    type Output = &'a [I::Ok];
+    type State = ();
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        _cx: &mut Context<'_>,
+        _state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        let remaining = input.remaining();
+        Poll::Ready(Ok(if self.n <= remaining.len() {
+            input.advance(self.n);
+            Status::Success(&remaining[..self.n], None)
+        } else {
+            let start = input.position();
+            input.as_mut().advance(remaining.len());
+            Status::Failure(
+                Error {
+                    expects: Expects::from("a token"),
+                    position: start..input.position(),
+                },
+                false,
+            )
+        }))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, F> Describe for SliceTakeWhile<'_, I, F> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::repeat(Grammar::Opaque, self.min..)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized, B> Describe for SliceTakeUntil<'_, I, B> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::repeat(
+            Grammar::sequence([
+                Grammar::NegativeLookahead(Box::new(Grammar::Opaque)),
+                Grammar::Any,
+            ]),
+            ..,
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized> Describe for SliceTake<'_, I> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::repeat(Grammar::Any, self.n..=self.n)
+    }
+}
+
+impl<'a, I, F> First<I::Ok> for SliceTakeWhile<'a, I, F>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: 'a,
+    F: FnMut(&I::Ok) -> bool,
+{
+    #[inline]
+    fn first(&mut self, token: &I::Ok) -> bool {
+        (self.cond)(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.min == 0
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from("<cond>"))
+    }
+}
+
+impl<'a, I, B> First<I::Ok> for SliceTakeUntil<'a, I, B>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: 'a,
+{
+    #[inline]
+    fn first(&mut self, _token: &I::Ok) -> bool {
+        true
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from("a token"))
+    }
+}
+
+impl<'a, I> First<I::Ok> for SliceTake<'a, I>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: 'a,
+{
+    #[inline]
+    fn first(&mut self, _token: &I::Ok) -> bool {
+        true
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.n == 0
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from("a token"))
+    }
+}
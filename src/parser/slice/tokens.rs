@@ -0,0 +1,106 @@
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use super::mismatch;
+use crate::error::{Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::Parser;
+use crate::stream::contiguous::Contiguous;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
+#[cfg(feature = "alloc")]
+use core::fmt::Display;
+
+/// A parser for function [`tokens`].
+///
+/// [`tokens`]: crate::parser::slice::tokens
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SliceTokens<'a, I: ?Sized, B> {
+    tokens: B,
+    _phantom: PhantomData<&'a I>,
+}
+
+impl<I: ?Sized, B> SliceTokens<'_, I, B> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(tokens: B) -> Self {
+        Self {
+            tokens,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, I, B> Parser<I> for SliceTokens<'a, I, B>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: PartialEq + 'a,
+    B: AsRef<[I::Ok]>,
+{
+    type Output = &'a [I::Ok];
+    type State = ();
+
+    fn poll_parse(
+        &mut self,
+        input: Pin<&mut I>,
+        _cx: &mut Context<'_>,
+        _state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        let remaining = input.remaining();
+        let tokens = self.tokens.as_ref();
+        Poll::Ready(Ok(if remaining.starts_with(tokens) {
+            input.advance(tokens.len());
+            Status::Success(&remaining[..tokens.len()], None)
+        } else {
+            let matched = remaining
+                .iter()
+                .zip(tokens)
+                .take_while(|(i, t)| i == t)
+                .count();
+            Status::Failure(mismatch(input, matched, Expects::from("<tokens>")), false)
+        }))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, I, B> Describe for SliceTokens<'a, I, B>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: Display + 'a,
+    B: AsRef<[I::Ok]>,
+{
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::sequence(
+            self.tokens
+                .as_ref()
+                .iter()
+                .map(|t| Grammar::Literal(t.to_string())),
+        )
+    }
+}
+
+impl<'a, I, B> First<I::Ok> for SliceTokens<'a, I, B>
+where
+    I: Contiguous<'a> + ?Sized,
+    I::Ok: PartialEq + 'a,
+    B: AsRef<[I::Ok]>,
+{
+    #[inline]
+    fn first(&mut self, token: &I::Ok) -> bool {
+        self.tokens.as_ref().first() == Some(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.tokens.as_ref().is_empty()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(Expects::from("<tokens>"))
+    }
+}
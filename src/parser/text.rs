@@ -0,0 +1,164 @@
+//! Parsers for textual inputs.
+mod indent;
+#[cfg(feature = "alloc")]
+mod string;
+mod trivia;
+
+pub use indent::{IndentedBlock, IndentedBlockState};
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub use string::{StringLiteral, StringLiteralState};
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub use trivia::{CapturedTrivia, CapturedTriviaState, TriviaPiece};
+pub use trivia::{Trivia, TriviaKind, TriviaState};
+
+use crate::parser::atomic::Tag;
+use crate::parser::combinator::Skip;
+use crate::parser::iterable::assert_iterable_parser;
+use crate::parser::Parser;
+use crate::stream::position::Column;
+use crate::stream::Input;
+#[cfg(feature = "alloc")]
+use crate::stream::Positioned;
+
+/// Parses a quoted string literal, and returns the unescaped contents.
+///
+/// The returned parser accepts JSON-style strings by default, and can be configured by the
+/// builder methods of [`StringLiteral`]. The contents are always collected into a [`String`],
+/// since streams of characters can't be borrowed as `str`.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::text::string_literal;
+///
+/// let mut parser = string_literal();
+/// let mut stream = stream::from_iter(r#""a\tb\u00e9\ud83d\ude00""#.chars()).positioned::<usize>();
+/// assert_eq!(parser.parse(&mut stream).await, Ok(String::from("a\tb\u{e9}\u{1f600}")));
+///
+/// let mut parser = string_literal()
+///     .escapes(&[('n', '\n'), ('\\', '\\'), ('"', '"')])
+///     .braced_unicode(true)
+///     .fixed_unicode(false)
+///     .raw(Some('r'));
+/// let mut stream = stream::from_iter(r##"r#"a "quoted" \n"#"##.chars()).positioned::<usize>();
+/// assert_eq!(parser.parse(&mut stream).await, Ok(String::from(r#"a "quoted" \n"#)));
+///
+/// // Errors point to the invalid escape sequence.
+/// let mut stream = stream::from_iter(r#""ab\q""#.chars()).positioned::<usize>();
+/// let err = parser.parse(&mut stream).await.unwrap_err();
+/// assert!(matches!(err, somen::error::ParseError::Parser(e) if e.position == (3..5)));
+/// # });
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[inline]
+pub fn string_literal<I>() -> StringLiteral<I>
+where
+    I: Positioned<Ok = char> + ?Sized,
+{
+    StringLiteral::new()
+}
+
+/// Skips whitespaces and comments.
+///
+/// The returned parser only skips whitespaces by default, and comments can be configured by the
+/// builder methods of [`Trivia`]. It always succeeds unless a block comment is left open.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::text::{trivia, TriviaKind};
+///
+/// let ws = trivia()
+///     .line_comments(&["//", "#"])
+///     .block_comment("/*", "*/")
+///     .nested(true);
+/// let mut parser = ws.symbol("let").prefix(ws.lexeme(tag("x")));
+/// let mut stream = stream::from_iter("let /* a /* b */ */ // c\n x # d".chars())
+///     .buffered_rewind()
+///     .positioned::<usize>();
+/// assert_eq!(parser.parse(&mut stream).await, Ok("x"));
+///
+/// // Trivia can be captured for formatters or documentation generators.
+/// let mut parser = ws.captured().skip(tag("/"));
+/// let mut stream = stream::from_iter("  // doc\n/".chars())
+///     .buffered_rewind()
+///     .positioned::<usize>();
+/// let pieces = parser.parse(&mut stream).await.unwrap();
+/// let kinds: Vec<_> = pieces.iter().map(|p| p.kind).collect();
+/// assert_eq!(
+///     kinds,
+///     [TriviaKind::Whitespace, TriviaKind::LineComment, TriviaKind::Whitespace],
+/// );
+/// assert_eq!(pieces[1].text, "// doc");
+/// assert_eq!(pieces[1].span, 2..8);
+/// # });
+/// ```
+#[inline]
+pub fn trivia<I>() -> Trivia<I>
+where
+    I: Input<Ok = char> + ?Sized,
+{
+    Trivia::new()
+}
+
+/// Parses a static string like [`tag`], then skips trailing whitespaces.
+///
+/// [`tag`]: crate::parser::tag
+#[inline]
+pub fn symbol<I>(symbol: &'static str) -> Skip<Tag<I>, Trivia<I>>
+where
+    I: Input<Ok = char> + ?Sized,
+{
+    Trivia::new().symbol(symbol)
+}
+
+/// Parses lines with `item`, which are indented deeper than the `parent` column.
+///
+/// All the items must start at the same column, and the block ends when the indentation returns
+/// to the `parent` column (or shallower). Blank lines between items are skipped by [`trivia`],
+/// which can be replaced by [`IndentedBlock::trivia`]. The stream must be positioned by a
+/// [`Column`] locator like [`LineCol`].
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::parser::text::indented_block;
+/// use somen::stream::position::LineCol;
+///
+/// let word = || is(|c: &char| c.is_alphanumeric()).repeat(1..).collect::<String>();
+/// let mut parser = position().then(|pos: LineCol| {
+///     word().skip(token(':')).and(indented_block(pos.col, word()).collect::<Vec<_>>())
+/// });
+///
+/// let mut stream = stream::from_iter("list:\n  a\n\n  b\nnext".chars())
+///     .buffered_rewind()
+///     .positioned::<LineCol>();
+/// assert_eq!(
+///     parser.parse(&mut stream).await,
+///     Ok((String::from("list"), vec![String::from("a"), String::from("b")])),
+/// );
+///
+/// // Misaligned lines are rejected.
+/// let mut stream = stream::from_iter("list:\n  a\n   b".chars())
+///     .buffered_rewind()
+///     .positioned::<LineCol>();
+/// assert!(parser.parse(&mut stream).await.is_err());
+/// # });
+/// ```
+///
+/// [`LineCol`]: crate::stream::position::LineCol
+#[inline]
+pub fn indented_block<P, I>(parent: usize, item: P) -> IndentedBlock<P, I>
+where
+    P: Parser<I>,
+    I: Input<Ok = char> + ?Sized,
+    I::Locator: Column,
+{
+    assert_iterable_parser(IndentedBlock::new(parent, item))
+}
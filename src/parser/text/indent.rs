@@ -0,0 +1,151 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use super::{Trivia, TriviaState};
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::iterable::IterableParser;
+use crate::parser::Parser;
+use crate::stream::position::Column;
+use crate::stream::Input;
+
+/// A iterable parser for function [`indented_block`].
+///
+/// [`indented_block`]: crate::parser::text::indented_block
+#[derive(Clone, Debug)]
+pub struct IndentedBlock<P, I: ?Sized> {
+    inner: P,
+    parent: usize,
+    trivia: Trivia<I>,
+}
+
+impl<P, I: ?Sized> IndentedBlock<P, I> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(parent: usize, inner: P) -> Self {
+        Self {
+            inner,
+            parent,
+            trivia: Trivia::new(),
+        }
+    }
+
+    /// Sets the parser to skip blank lines and comments between items. (default: [`trivia`])
+    ///
+    /// [`trivia`]: crate::parser::text::trivia
+    #[inline]
+    pub fn trivia(mut self, trivia: Trivia<I>) -> Self {
+        self.trivia = trivia;
+        self
+    }
+
+    /// Extracts the inner parser.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Phase {
+    #[default]
+    Trivia,
+    Peek,
+    Item,
+}
+
+crate::parser_state! {
+    pub struct IndentedBlockState<I: Input, P: Parser> {
+        phase: Phase,
+        trivia: TriviaState<I>,
+        inner: P::State,
+        #[opt(try_set = set_marker)]
+        marker: I::Marker,
+        #[opt]
+        peek: I::Marker,
+        level: Option<usize>,
+    }
+}
+
+impl<P, I> IterableParser<I> for IndentedBlock<P, I>
+where
+    P: Parser<I>,
+    I: Input<Ok = char> + ?Sized,
+    I::Locator: Column,
+{
+    type Item = P::Output;
+    type State = IndentedBlockState<I, P>;
+
+    fn poll_parse_next(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Option<Self::Item>, I> {
+        loop {
+            match state.phase {
+                Phase::Trivia => {
+                    state.set_marker(|| input.as_mut().mark())?;
+                    match ready!(self
+                        .trivia
+                        .poll_parse(input.as_mut(), cx, &mut state.trivia)?)
+                    {
+                        Status::Success((), _) => {
+                            state.trivia = Default::default();
+                            state.peek = Some(input.as_mut().mark()?);
+                            state.phase = Phase::Peek;
+                        }
+                        Status::Failure(err, exclusive) => {
+                            input.drop_marker(state.marker())?;
+                            return Poll::Ready(Ok(Status::Failure(err, exclusive)));
+                        }
+                    }
+                }
+                Phase::Peek => {
+                    let parsed = ready!(input.as_mut().try_poll_next(cx)?);
+                    input.as_mut().rewind(state.peek())?;
+                    let column = input.position().column();
+
+                    let expects = match state.level {
+                        None if parsed.is_some() && column > self.parent => {
+                            state.level = Some(column);
+                            None
+                        }
+                        None => Some("an indented block"),
+                        Some(level) if parsed.is_some() && column == level => None,
+                        // Dedented to the parent (or outer) level, the block ends here.
+                        Some(_) if parsed.is_none() || column <= self.parent => {
+                            input.rewind(state.marker())?;
+                            return Poll::Ready(Ok(Status::Success(None, None)));
+                        }
+                        Some(_) => Some("correct indentation"),
+                    };
+
+                    input.as_mut().drop_marker(state.marker())?;
+                    if let Some(expects) = expects {
+                        return Poll::Ready(Ok(Status::Failure(
+                            Error {
+                                expects: Expects::from(expects),
+                                position: input.position()..input.position(),
+                            },
+                            false,
+                        )));
+                    }
+                    state.phase = Phase::Item;
+                }
+                Phase::Item => {
+                    let res =
+                        ready!(self
+                            .inner
+                            .poll_parse(input.as_mut(), cx, &mut state.inner)?);
+                    state.inner = Default::default();
+                    state.phase = Phase::Trivia;
+                    return Poll::Ready(Ok(match res {
+                        Status::Success(val, err) => Status::Success(Some(val), err),
+                        Status::Failure(err, exclusive) => Status::Failure(err, exclusive),
+                    }));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,409 @@
+use alloc::string::String;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::Parser;
+use crate::stream::Positioned;
+
+/// A parser for function [`string_literal`].
+///
+/// [`string_literal`]: crate::parser::text::string_literal
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StringLiteral<I: ?Sized> {
+    quotes: &'static str,
+    escape: Option<char>,
+    escapes: &'static [(char, char)],
+    fixed_unicode: bool,
+    braced_unicode: bool,
+    hex: bool,
+    surrogates: bool,
+    raw: Option<char>,
+    control: bool,
+    _phantom: PhantomData<I>,
+}
+
+/// Simple escape sequences for JSON strings.
+const JSON_ESCAPES: &[(char, char)] = &[
+    ('"', '"'),
+    ('\\', '\\'),
+    ('/', '/'),
+    ('b', '\x08'),
+    ('f', '\x0c'),
+    ('n', '\n'),
+    ('r', '\r'),
+    ('t', '\t'),
+];
+
+impl<I: ?Sized> Default for StringLiteral<I> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            quotes: "\"",
+            escape: Some('\\'),
+            escapes: JSON_ESCAPES,
+            fixed_unicode: true,
+            braced_unicode: false,
+            hex: false,
+            surrogates: true,
+            raw: None,
+            control: false,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<I: ?Sized> StringLiteral<I> {
+    /// Creates a new instance, which parses JSON-style strings.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets characters which can be used as quotes. (default: `"`)
+    ///
+    /// A string must be closed by the same character it was opened with.
+    #[inline]
+    pub fn quotes(mut self, quotes: &'static str) -> Self {
+        self.quotes = quotes;
+        self
+    }
+
+    /// Sets the escape character, or disables escape sequences by [`None`]. (default: `\`)
+    #[inline]
+    pub fn escape(mut self, escape: Option<char>) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /// Sets the table of simple escape sequences, pairs of a character following the escape
+    /// character and a character it represents. (default: the escape sequences of JSON)
+    #[inline]
+    pub fn escapes(mut self, escapes: &'static [(char, char)]) -> Self {
+        self.escapes = escapes;
+        self
+    }
+
+    /// Enables or disables escape sequences like `\uXXXX`. (default: `true`)
+    #[inline]
+    pub fn fixed_unicode(mut self, enabled: bool) -> Self {
+        self.fixed_unicode = enabled;
+        self
+    }
+
+    /// Enables or disables escape sequences like `\u{XXXXXX}`. (default: `false`)
+    #[inline]
+    pub fn braced_unicode(mut self, enabled: bool) -> Self {
+        self.braced_unicode = enabled;
+        self
+    }
+
+    /// Enables or disables escape sequences like `\xHH`. (default: `false`)
+    #[inline]
+    pub fn hex(mut self, enabled: bool) -> Self {
+        self.hex = enabled;
+        self
+    }
+
+    /// Enables or disables joining UTF-16 surrogate pairs like `\ud83d\ude00`. (default: `true`)
+    ///
+    /// If it is disabled, surrogates will be rejected as invalid codepoints.
+    #[inline]
+    pub fn surrogate_pairs(mut self, enabled: bool) -> Self {
+        self.surrogates = enabled;
+        self
+    }
+
+    /// Enables raw strings prefixed by the character, or disables them by [`None`]. (default:
+    /// [`None`])
+    ///
+    /// Raw strings don't interpret escape sequences, and can be fenced by any number of `#`s like
+    /// `r#"..."#`.
+    #[inline]
+    pub fn raw(mut self, prefix: Option<char>) -> Self {
+        self.raw = prefix;
+        self
+    }
+
+    /// Allows or rejects raw control characters U+0000 to U+001F in strings, like tabs and line
+    /// feeds. (default: `false`)
+    ///
+    /// Other control characters like U+007F are always allowed, as in JSON.
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use somen::prelude::*;
+    /// use somen::parser::text::string_literal;
+    ///
+    /// let mut stream = stream::from_iter("\"a\tb\"".chars()).positioned::<usize>();
+    /// assert!(string_literal().parse(&mut stream).await.is_err());
+    ///
+    /// let mut stream = stream::from_iter("\"a\u{7f}b\"".chars()).positioned::<usize>();
+    /// assert_eq!(string_literal().parse(&mut stream).await, Ok(String::from("a\u{7f}b")));
+    ///
+    /// let mut parser = string_literal().control_chars(true);
+    /// let mut stream = stream::from_iter("\"a\tb\"".chars()).positioned::<usize>();
+    /// assert_eq!(parser.parse(&mut stream).await, Ok(String::from("a\tb")));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn control_chars(mut self, allowed: bool) -> Self {
+        self.control = allowed;
+        self
+    }
+
+    fn rejects(&self, c: char) -> bool {
+        !self.control && c < '\u{20}'
+    }
+
+    fn simple_escape(&self, c: char) -> Option<char> {
+        self.escapes
+            .iter()
+            .find_map(|&(from, to)| if from == c { Some(to) } else { None })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Phase {
+    #[default]
+    Open,
+    RawFence(usize),
+    Body(char),
+    Escape(char),
+    Unicode(char),
+    Digits {
+        quote: char,
+        kind: Digits,
+        value: u32,
+        count: usize,
+    },
+    LowEscape(char, u32),
+    LowUnicode(char, u32),
+    RawBody(char, usize),
+    RawClose(char, usize, usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Digits {
+    Fixed(Option<u32>),
+    Braced,
+    Hex,
+}
+
+crate::parser_state! {
+    pub struct StringLiteralState<I> {
+        phase: Phase,
+        output: String,
+        #[opt(set = set_start)]
+        start: I::Locator,
+        #[opt]
+        escape_start: I::Locator,
+    }
+}
+
+impl<I> Parser<I> for StringLiteral<I>
+where
+    I: Positioned<Ok = char> + ?Sized,
+{
+    type Output = String;
+    type State = StringLiteralState<I>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        state.set_start(|| input.position());
+
+        macro_rules! fail {
+            ($expects:expr, $start:expr) => {
+                return Poll::Ready(Ok(Status::Failure(
+                    Error {
+                        expects: Expects::from($expects),
+                        position: $start..input.position(),
+                    },
+                    false,
+                )))
+            };
+        }
+
+        loop {
+            let pos = input.position();
+            let parsed = ready!(input.as_mut().try_poll_next(cx)?);
+            state.phase = match (state.phase, parsed) {
+                (Phase::Open, Some(c)) if self.raw == Some(c) => Phase::RawFence(0),
+                (Phase::Open, Some(c)) if self.quotes.contains(c) => Phase::Body(c),
+                (Phase::Open, _) => fail!("a string literal", state.start()),
+                (Phase::RawFence(n), Some('#')) => Phase::RawFence(n + 1),
+                (Phase::RawFence(n), Some(c)) if self.quotes.contains(c) => Phase::RawBody(c, n),
+                (Phase::RawFence(_), _) => fail!("a quote", pos),
+                (Phase::Body(quote), Some(c)) if c == quote => {
+                    return Poll::Ready(Ok(Status::Success(state.output(), None)));
+                }
+                (Phase::Body(quote), Some(c)) if Some(c) == self.escape => {
+                    state.escape_start = Some(pos);
+                    Phase::Escape(quote)
+                }
+                (Phase::Body(_), Some(c)) if self.rejects(c) => {
+                    fail!("a non-control character", pos)
+                }
+                (Phase::Body(quote), Some(c)) => {
+                    state.output.push(c);
+                    Phase::Body(quote)
+                }
+                (Phase::Escape(quote), Some(c)) => match self.simple_escape(c) {
+                    Some(e) => {
+                        state.output.push(e);
+                        Phase::Body(quote)
+                    }
+                    None if c == 'u' && (self.fixed_unicode || self.braced_unicode) => {
+                        Phase::Unicode(quote)
+                    }
+                    None if c == 'x' && self.hex => Phase::Digits {
+                        quote,
+                        kind: Digits::Hex,
+                        value: 0,
+                        count: 0,
+                    },
+                    None => fail!("a valid escape sequence", state.escape_start()),
+                },
+                (Phase::Unicode(quote), Some('{')) if self.braced_unicode => Phase::Digits {
+                    quote,
+                    kind: Digits::Braced,
+                    value: 0,
+                    count: 0,
+                },
+                (Phase::Unicode(quote), Some(c)) if self.fixed_unicode && c.is_ascii_hexdigit() => {
+                    Phase::Digits {
+                        quote,
+                        kind: Digits::Fixed(None),
+                        value: c.to_digit(16).unwrap(),
+                        count: 1,
+                    }
+                }
+                (Phase::Unicode(_), _) => fail!("a valid escape sequence", state.escape_start()),
+                (
+                    Phase::Digits {
+                        quote,
+                        kind: Digits::Braced,
+                        value,
+                        count,
+                    },
+                    Some('}'),
+                ) if count > 0 => match char::from_u32(value) {
+                    Some(c) => {
+                        state.output.push(c);
+                        Phase::Body(quote)
+                    }
+                    None => fail!("a valid unicode codepoint", state.escape_start()),
+                },
+                (
+                    Phase::Digits {
+                        quote,
+                        kind,
+                        value,
+                        count,
+                    },
+                    Some(c),
+                ) if c.is_ascii_hexdigit() && (kind != Digits::Braced || count < 6) => {
+                    let value = value * 16 + c.to_digit(16).unwrap();
+                    let count = count + 1;
+                    match kind {
+                        Digits::Hex if count == 2 => {
+                            state.output.push(char::from(value as u8));
+                            Phase::Body(quote)
+                        }
+                        Digits::Fixed(None)
+                            if count == 4
+                                && self.surrogates
+                                && (0xD800..0xDC00).contains(&value) =>
+                        {
+                            Phase::LowEscape(quote, value)
+                        }
+                        Digits::Fixed(high) if count == 4 => {
+                            let value = match high {
+                                Some(high) if (0xDC00..0xE000).contains(&value) => {
+                                    0x10000 + ((high - 0xD800) << 10) + (value - 0xDC00)
+                                }
+                                Some(_) => fail!("a low surrogate", state.escape_start()),
+                                None => value,
+                            };
+                            match char::from_u32(value) {
+                                Some(c) => {
+                                    state.output.push(c);
+                                    Phase::Body(quote)
+                                }
+                                None => fail!("a valid unicode codepoint", state.escape_start()),
+                            }
+                        }
+                        kind => Phase::Digits {
+                            quote,
+                            kind,
+                            value,
+                            count,
+                        },
+                    }
+                }
+                (Phase::Digits { .. }, _) => fail!("a hex digit", pos),
+                (Phase::LowEscape(quote, high), Some(c)) if Some(c) == self.escape => {
+                    Phase::LowUnicode(quote, high)
+                }
+                (Phase::LowUnicode(quote, high), Some('u')) => Phase::Digits {
+                    quote,
+                    kind: Digits::Fixed(Some(high)),
+                    value: 0,
+                    count: 0,
+                },
+                (Phase::LowEscape(..), _) | (Phase::LowUnicode(..), _) => {
+                    fail!("a low surrogate", state.escape_start())
+                }
+                (Phase::RawBody(quote, 0), Some(c)) if c == quote => {
+                    return Poll::Ready(Ok(Status::Success(state.output(), None)));
+                }
+                (Phase::RawBody(quote, n), Some(c)) if c == quote => Phase::RawClose(quote, n, 0),
+                (Phase::RawBody(_, _), Some(c)) if self.rejects(c) => {
+                    fail!("a non-control character", pos)
+                }
+                (Phase::RawBody(quote, n), Some(c)) => {
+                    state.output.push(c);
+                    Phase::RawBody(quote, n)
+                }
+                (Phase::RawClose(quote, n, seen), Some('#')) => {
+                    if seen + 1 == n {
+                        return Poll::Ready(Ok(Status::Success(state.output(), None)));
+                    }
+                    Phase::RawClose(quote, n, seen + 1)
+                }
+                (Phase::RawClose(quote, n, seen), Some(c)) => {
+                    // The quote and fences were a part of contents.
+                    state.output.push(quote);
+                    state.output.extend(core::iter::repeat_n('#', seen));
+                    if c == quote {
+                        Phase::RawClose(quote, n, 0)
+                    } else if self.rejects(c) {
+                        fail!("a non-control character", pos)
+                    } else {
+                        state.output.push(c);
+                        Phase::RawBody(quote, n)
+                    }
+                }
+                (Phase::Body(_), None)
+                | (Phase::Escape(_), None)
+                | (Phase::RawBody(_, _), None)
+                | (Phase::RawClose(_, _, _), None) => fail!("a closing quote", pos),
+            };
+        }
+    }
+}
+
+impl<I: ?Sized> Describe for StringLiteral<I> {
+    #[inline]
+    fn describe_with(&self, _describer: &mut Describer) -> Grammar {
+        Grammar::Special("string literal".into())
+    }
+}
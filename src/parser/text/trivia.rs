@@ -0,0 +1,512 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Error, Expects, PolledResult, Status};
+#[cfg(feature = "alloc")]
+use crate::parser::assert_parser;
+use crate::parser::atomic::Tag;
+use crate::parser::combinator::Skip;
+use crate::parser::{tag, Parser, ParserExt};
+use crate::stream::Input;
+
+/// The maximum length of comment delimiters.
+const MAX_DELIMITER: usize = 8;
+
+/// A parser for function [`trivia`].
+///
+/// [`trivia`]: crate::parser::text::trivia
+pub struct Trivia<I: ?Sized> {
+    is_space: fn(char) -> bool,
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    nested: bool,
+    _phantom: PhantomData<I>,
+}
+
+impl<I: ?Sized> Clone for Trivia<I> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I: ?Sized> Copy for Trivia<I> {}
+
+impl<I: ?Sized> fmt::Debug for Trivia<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Trivia")
+            .field("line_comments", &self.line_comments)
+            .field("block_comment", &self.block_comment)
+            .field("nested", &self.nested)
+            .finish()
+    }
+}
+
+impl<I: ?Sized> Default for Trivia<I> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            is_space: char::is_whitespace,
+            line_comments: &[],
+            block_comment: None,
+            nested: false,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<I: ?Sized> Trivia<I> {
+    /// Creates a new instance, which only skips whitespaces.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the condition for whitespace characters. (default: [`char::is_whitespace`])
+    #[inline]
+    pub fn spaces(mut self, is_space: fn(char) -> bool) -> Self {
+        self.is_space = is_space;
+        self
+    }
+
+    /// Sets prefixes of line comments, like `//` or `#`.
+    ///
+    /// # Panics
+    /// if there are more than 31 prefixes or some of them are empty or longer than 8 characters.
+    #[inline]
+    pub fn line_comments(mut self, prefixes: &'static [&'static str]) -> Self {
+        assert!(prefixes.len() < 32, "too many line comment prefixes");
+        assert!(
+            prefixes.iter().all(|p| valid_delimiter(p)),
+            "invalid line comment prefix"
+        );
+        self.line_comments = prefixes;
+        self
+    }
+
+    /// Sets delimiters of block comments, like `/*` and `*/`.
+    ///
+    /// # Panics
+    /// if the delimiters are empty or longer than 8 characters.
+    #[inline]
+    pub fn block_comment(mut self, open: &'static str, close: &'static str) -> Self {
+        assert!(
+            valid_delimiter(open) && valid_delimiter(close),
+            "invalid block comment delimiter"
+        );
+        self.block_comment = Some((open, close));
+        self
+    }
+
+    /// Allows or disallows nesting block comments. (default: `false`)
+    #[inline]
+    pub fn nested(mut self, nested: bool) -> Self {
+        self.nested = nested;
+        self
+    }
+
+    /// Returns a parser that parses with `p`, then skips the trailing trivia.
+    #[inline]
+    pub fn lexeme<P>(self, p: P) -> Skip<P, Self>
+    where
+        I: Input<Ok = char>,
+        P: Parser<I>,
+    {
+        p.skip(self)
+    }
+
+    /// Returns a parser that parses the string like [`tag`], then skips the trailing trivia.
+    ///
+    /// [`tag`]: crate::parser::tag
+    #[inline]
+    pub fn symbol(self, symbol: &'static str) -> Skip<Tag<I>, Self>
+    where
+        I: Input<Ok = char>,
+    {
+        tag(symbol).skip(self)
+    }
+
+    /// Returns a parser that records the skipped trivia, instead of discarding it.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn captured(self) -> CapturedTrivia<I>
+    where
+        I: Input<Ok = char>,
+    {
+        assert_parser(CapturedTrivia { inner: self })
+    }
+
+    /// Lists opening delimiters of comments, the block comment comes last.
+    fn delimiter(&self, index: usize) -> &'static str {
+        match self.line_comments.get(index) {
+            Some(prefix) => prefix,
+            None => self.block_comment.unwrap().0,
+        }
+    }
+
+    fn delimiters(&self) -> usize {
+        self.line_comments.len() + self.block_comment.is_some() as usize
+    }
+
+    /// Switches into a comment if one of the delimiters has been matched.
+    fn advance(&self, alive: u32, matched: usize) -> (Phase, Option<&'static str>) {
+        for i in 0..self.delimiters() {
+            let delim = self.delimiter(i);
+            if alive & (1 << i) != 0 && delim.chars().count() == matched {
+                let phase = if i < self.line_comments.len() {
+                    Phase::Line
+                } else {
+                    Phase::Block { depth: 1 }
+                };
+                return (phase, Some(delim));
+            }
+        }
+        (Phase::Prefix { alive, matched }, None)
+    }
+}
+
+#[inline]
+fn valid_delimiter(delim: &str) -> bool {
+    let len = delim.chars().count();
+    len > 0 && len <= MAX_DELIMITER
+}
+
+/// Kinds of trivia.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TriviaKind {
+    /// A sequence of whitespaces.
+    Whitespace,
+    /// A line comment, without the trailing newline.
+    LineComment,
+    /// A block comment.
+    BlockComment,
+}
+
+/// A piece of trivia recorded by [`CapturedTrivia`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TriviaPiece<L> {
+    /// The kind of the trivia.
+    pub kind: TriviaKind,
+    /// The original text, including comment delimiters.
+    pub text: String,
+    /// The position of the trivia.
+    pub span: Range<L>,
+}
+
+/// Receives characters of trivia while parsing.
+trait Record<L> {
+    fn start(&mut self, kind: TriviaKind, pos: L);
+    fn push(&mut self, c: char);
+    fn push_str(&mut self, s: &str);
+    fn finish(&mut self, pos: L);
+}
+
+impl<L> Record<L> for () {
+    #[inline]
+    fn start(&mut self, _kind: TriviaKind, _pos: L) {}
+    #[inline]
+    fn push(&mut self, _c: char) {}
+    #[inline]
+    fn push_str(&mut self, _s: &str) {}
+    #[inline]
+    fn finish(&mut self, _pos: L) {}
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Phase {
+    #[default]
+    Boundary,
+    Whitespace,
+    Prefix {
+        alive: u32,
+        matched: usize,
+    },
+    Line,
+    Block {
+        depth: usize,
+    },
+}
+
+crate::parser_state! {
+    pub struct TriviaState<I: Input> {
+        phase: Phase,
+        #[opt]
+        marker: I::Marker,
+        #[opt]
+        start: I::Locator,
+        window: ([char; MAX_DELIMITER], usize),
+    }
+}
+
+impl<I: Input<Ok = char> + ?Sized> TriviaState<I> {
+    fn push_window(&mut self, c: char) {
+        let (buf, len) = &mut self.window;
+        if *len == MAX_DELIMITER {
+            buf.copy_within(1.., 0);
+            buf[MAX_DELIMITER - 1] = c;
+        } else {
+            buf[*len] = c;
+            *len += 1;
+        }
+    }
+
+    fn window_ends_with(&self, delim: &str) -> bool {
+        let (buf, len) = &self.window;
+        let count = delim.chars().count();
+        count <= *len && buf[*len - count..*len].iter().copied().eq(delim.chars())
+    }
+}
+
+fn poll_trivia<I, R>(
+    trivia: &Trivia<I>,
+    mut input: Pin<&mut I>,
+    cx: &mut Context<'_>,
+    state: &mut TriviaState<I>,
+    record: &mut R,
+) -> PolledResult<(), I>
+where
+    I: Input<Ok = char> + ?Sized,
+    R: Record<I::Locator>,
+{
+    loop {
+        let mut opened = None;
+        if matches!(state.phase, Phase::Boundary | Phase::Whitespace) && state.marker.is_none() {
+            state.marker = Some(input.as_mut().mark()?);
+            state.start = Some(input.position());
+        }
+
+        let pos = input.position();
+        // The end of a line comment is also the start of following whitespaces.
+        let line_end = (state.phase == Phase::Line).then(|| input.position());
+        let parsed = ready!(input.as_mut().try_poll_next(cx)?);
+        state.phase = match (state.phase, parsed) {
+            (Phase::Boundary | Phase::Whitespace, Some(c)) if (trivia.is_space)(c) => {
+                input.as_mut().drop_marker(state.marker())?;
+                if state.phase == Phase::Boundary {
+                    record.start(TriviaKind::Whitespace, state.start());
+                }
+                record.push(c);
+                Phase::Whitespace
+            }
+            (phase @ (Phase::Boundary | Phase::Whitespace), Some(c)) => {
+                if phase == Phase::Whitespace {
+                    record.finish(pos);
+                }
+                let mut alive = 0;
+                for i in 0..trivia.delimiters() {
+                    if trivia.delimiter(i).starts_with(c) {
+                        alive |= 1 << i;
+                    }
+                }
+                if alive == 0 {
+                    input.rewind(state.marker())?;
+                    return Poll::Ready(Ok(Status::Success((), None)));
+                }
+                let (phase, delim) = trivia.advance(alive, 1);
+                opened = delim;
+                phase
+            }
+            (Phase::Prefix { alive, matched }, Some(c)) => {
+                let mut next = 0;
+                for i in 0..trivia.delimiters() {
+                    if alive & (1 << i) != 0 && trivia.delimiter(i).chars().nth(matched) == Some(c)
+                    {
+                        next |= 1 << i;
+                    }
+                }
+                if next == 0 {
+                    input.rewind(state.marker())?;
+                    return Poll::Ready(Ok(Status::Success((), None)));
+                }
+                let (phase, delim) = trivia.advance(next, matched + 1);
+                opened = delim;
+                phase
+            }
+            (Phase::Line, Some('\n')) => {
+                // The newline is regarded as a whitespace following the comment.
+                record.finish(pos);
+                record.start(TriviaKind::Whitespace, line_end.unwrap());
+                record.push('\n');
+                Phase::Whitespace
+            }
+            (Phase::Line, Some(c)) => {
+                record.push(c);
+                Phase::Line
+            }
+            (Phase::Block { depth }, Some(c)) => {
+                record.push(c);
+                state.push_window(c);
+                let (open, close) = trivia.block_comment.unwrap();
+                if state.window_ends_with(close) {
+                    state.window.1 = 0;
+                    if depth == 1 {
+                        record.finish(input.position());
+                        Phase::Boundary
+                    } else {
+                        Phase::Block { depth: depth - 1 }
+                    }
+                } else if trivia.nested && state.window_ends_with(open) {
+                    state.window.1 = 0;
+                    Phase::Block { depth: depth + 1 }
+                } else {
+                    Phase::Block { depth }
+                }
+            }
+            (Phase::Whitespace, None) | (Phase::Line, None) => {
+                record.finish(pos);
+                if let Some(marker) = state.marker.take() {
+                    input.drop_marker(marker)?;
+                }
+                return Poll::Ready(Ok(Status::Success((), None)));
+            }
+            (Phase::Boundary, None) => {
+                input.rewind(state.marker())?;
+                return Poll::Ready(Ok(Status::Success((), None)));
+            }
+            (Phase::Prefix { .. }, None) => {
+                input.rewind(state.marker())?;
+                return Poll::Ready(Ok(Status::Success((), None)));
+            }
+            (Phase::Block { .. }, None) => {
+                return Poll::Ready(Ok(Status::Failure(
+                    Error {
+                        expects: Expects::from("the end of the comment"),
+                        position: pos..input.position(),
+                    },
+                    false,
+                )));
+            }
+        };
+
+        if let Some(delim) = opened {
+            input.as_mut().drop_marker(state.marker())?;
+            let start = state.start();
+            match state.phase {
+                Phase::Line => record.start(TriviaKind::LineComment, start),
+                _ => record.start(TriviaKind::BlockComment, start),
+            }
+            record.push_str(delim);
+            state.window.1 = 0;
+        }
+    }
+}
+
+impl<I> Parser<I> for Trivia<I>
+where
+    I: Input<Ok = char> + ?Sized,
+{
+    type Output = ();
+    type State = TriviaState<I>;
+
+    #[inline]
+    fn poll_parse(
+        &mut self,
+        input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        poll_trivia(self, input, cx, state, &mut ())
+    }
+}
+
+/// A parser for method [`Trivia::captured`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[derive(Clone, Copy, Debug)]
+pub struct CapturedTrivia<I: ?Sized> {
+    inner: Trivia<I>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ?Sized> CapturedTrivia<I> {
+    /// Extracts the inner parser.
+    #[inline]
+    pub fn into_inner(self) -> Trivia<I> {
+        self.inner
+    }
+}
+
+#[cfg(feature = "alloc")]
+struct Recorder<'a, L> {
+    pieces: &'a mut Vec<TriviaPiece<L>>,
+    current: &'a mut Option<(TriviaKind, L, String)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<L> Record<L> for Recorder<'_, L> {
+    #[inline]
+    fn start(&mut self, kind: TriviaKind, pos: L) {
+        *self.current = Some((kind, pos, String::new()));
+    }
+
+    #[inline]
+    fn push(&mut self, c: char) {
+        if let Some((_, _, text)) = self.current {
+            text.push(c);
+        }
+    }
+
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        if let Some((_, _, text)) = self.current {
+            text.push_str(s);
+        }
+    }
+
+    #[inline]
+    fn finish(&mut self, pos: L) {
+        if let Some((kind, start, text)) = self.current.take() {
+            self.pieces.push(TriviaPiece {
+                kind,
+                text,
+                span: start..pos,
+            });
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+crate::parser_state! {
+    pub struct CapturedTriviaState<I: Input> {
+        inner: TriviaState<I>,
+        pieces: Vec<TriviaPiece<I::Locator>>,
+        current: Option<(TriviaKind, I::Locator, String)>,
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Parser<I> for CapturedTrivia<I>
+where
+    I: Input<Ok = char> + ?Sized,
+{
+    type Output = Vec<TriviaPiece<I::Locator>>;
+    type State = CapturedTriviaState<I>;
+
+    fn poll_parse(
+        &mut self,
+        input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        let mut recorder = Recorder {
+            pieces: &mut state.pieces,
+            current: &mut state.current,
+        };
+        poll_trivia(&self.inner, input, cx, &mut state.inner, &mut recorder).map_ok(|status| {
+            match status {
+                Status::Success((), err) => Status::Success(state.pieces(), err),
+                Status::Failure(err, exclusive) => Status::Failure(err, exclusive),
+            }
+        })
+    }
+}
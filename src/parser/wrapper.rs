@@ -1,4 +1,6 @@
 //! Parsers to convert parse results or parser types.
+mod context;
+mod cut;
 mod discard;
 mod either;
 mod exclusive;
@@ -6,12 +8,18 @@ mod expect;
 mod lazy;
 mod map;
 mod map_err;
+mod nested;
 mod no_state;
+#[cfg(feature = "alloc")]
+mod node;
 mod rewindable;
 mod satisfy;
 mod spanned;
+mod trace;
 mod with_position;
 
+pub use context::{MapCtx, SatisfyCtx};
+pub use cut::Cut;
 pub use discard::Discard;
 pub use either::Either;
 pub use exclusive::Exclusive;
@@ -19,8 +27,13 @@ pub use expect::Expect;
 pub use lazy::Lazy;
 pub use map::{Map, TryMap};
 pub use map_err::MapErr;
+pub use nested::Nested;
 pub use no_state::NoState;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub use node::{Node, NodeState};
 pub use rewindable::Rewindable;
 pub use satisfy::Satisfy;
 pub use spanned::Spanned;
+pub use trace::Trace;
 pub use with_position::WithPosition;
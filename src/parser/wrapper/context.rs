@@ -0,0 +1,172 @@
+use core::pin::Pin;
+use core::task::Context;
+
+use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::Parser;
+use crate::stream::context::Contextual;
+use crate::stream::Positioned;
+
+/// A parser for method [`map_ctx`].
+///
+/// [`map_ctx`]: crate::parser::ParserExt::map_ctx
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MapCtx<P, F> {
+    inner: P,
+    f: F,
+}
+
+impl<P, F> MapCtx<P, F> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: P, f: F) -> Self {
+        Self { inner, f }
+    }
+
+    /// Extracts the inner parser.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P, F, O, I> Parser<I> for MapCtx<P, F>
+where
+    P: Parser<I>,
+    F: FnMut(P::Output, &mut I::Ctx) -> O,
+    I: Positioned + Contextual + ?Sized,
+{
+    type Output = O;
+    type State = P::State;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        self.inner
+            .poll_parse(input.as_mut(), cx, state)
+            .map_ok(|status| match status {
+                Status::Success(val, err) => Status::Success((self.f)(val, input.ctx_mut()), err),
+                Status::Failure(err, exclusive) => Status::Failure(err, exclusive),
+            })
+    }
+}
+
+/// A parser for method [`satisfy_ctx`].
+///
+/// [`satisfy_ctx`]: crate::parser::ParserExt::satisfy_ctx
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SatisfyCtx<P, F> {
+    inner: P,
+    f: F,
+}
+
+impl<P, F> SatisfyCtx<P, F> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: P, f: F) -> Self {
+        Self { inner, f }
+    }
+
+    /// Extracts the inner parser.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+crate::parser_state! {
+    pub struct SatisfyCtxState<I, P: Parser> {
+        inner: P::State,
+        #[opt(set = set_start)]
+        start: I::Locator,
+    }
+}
+
+impl<P, F, I> Parser<I> for SatisfyCtx<P, F>
+where
+    P: Parser<I>,
+    F: FnMut(&P::Output, &mut I::Ctx) -> bool,
+    I: Positioned + Contextual + ?Sized,
+{
+    type Output = P::Output;
+    type State = SatisfyCtxState<I, P>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        state.set_start(|| input.position());
+        self.inner
+            .poll_parse(input.as_mut(), cx, &mut state.inner)
+            .map_ok(|status| match status {
+                Status::Success(val, err) if (self.f)(&val, input.as_mut().ctx_mut()) => {
+                    Status::Success(val, err)
+                }
+                Status::Success(_, _) => Status::Failure(
+                    Error {
+                        expects: Expects::from("<condition>"),
+                        position: state.start()..input.position(),
+                    },
+                    false,
+                ),
+                Status::Failure(err, exclusive) => Status::Failure(err, exclusive),
+            })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, F> Describe for MapCtx<P, F> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        self.inner.describe_with(describer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: Describe, F> Describe for SatisfyCtx<P, F> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        self.inner.describe_with(describer)
+    }
+}
+
+impl<T, P: First<T>, F> First<T> for MapCtx<P, F> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.inner.first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        self.inner.first_expects()
+    }
+}
+
+impl<T, P: First<T>, F> First<T> for SatisfyCtx<P, F> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.inner.first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        self.inner.first_expects()
+    }
+}
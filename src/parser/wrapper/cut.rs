@@ -0,0 +1,89 @@
+use core::pin::Pin;
+use core::task::Context;
+
+use crate::error::{Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::Parser;
+use crate::stream::Positioned;
+
+/// A parser for method [`cut`].
+///
+/// [`cut`]: crate::parser::ParserExt::cut
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cut<P> {
+    inner: P,
+}
+
+impl<P> Cut<P> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: P) -> Self {
+        Self { inner }
+    }
+
+    /// Extracts the inner parser.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P, I> Parser<I> for Cut<P>
+where
+    P: Parser<I>,
+    I: Positioned + ?Sized,
+{
+    type Output = P::Output;
+    type State = P::State;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        self.inner
+            .poll_parse(input.as_mut(), cx, state)
+            .map_ok(|status| match status {
+                Status::Failure(err, _) => Status::Failure(err, true),
+                res => res,
+            })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: Describe> Describe for Cut<P> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        self.inner.describe_with(describer)
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>> Generate<T> for Cut<P> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        self.inner.generate(gen)
+    }
+}
+
+impl<T, P: First<T>> First<T> for Cut<P> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.inner.first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        self.inner.first_expects()
+    }
+}
@@ -1,7 +1,12 @@
 use core::pin::Pin;
 use core::task::Context;
 
-use crate::error::PolledResult;
+use crate::error::{Expects, PolledResult};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::Parser;
 use crate::stream::Positioned;
@@ -105,3 +110,51 @@ where
         }
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<T: Describe, U: Describe> Describe for Either<T, U> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        match self {
+            Self::Left(left) => left.describe_with(describer),
+            Self::Right(right) => right.describe_with(describer),
+        }
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<X, T: Generate<X>, U: Generate<X>> Generate<X> for Either<T, U> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, X>) -> Result<(), GenerateError> {
+        match self {
+            Self::Left(inner) => inner.generate(gen),
+            Self::Right(inner) => inner.generate(gen),
+        }
+    }
+}
+
+impl<X, T: First<X>, U: First<X>> First<X> for Either<T, U> {
+    #[inline]
+    fn first(&mut self, token: &X) -> bool {
+        match self {
+            Self::Left(left) => left.first(token),
+            Self::Right(right) => right.first(token),
+        }
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        match self {
+            Self::Left(left) => left.nullable(),
+            Self::Right(right) => right.nullable(),
+        }
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        match self {
+            Self::Left(left) => left.first_expects(),
+            Self::Right(right) => right.first_expects(),
+        }
+    }
+}
@@ -2,8 +2,15 @@ use core::pin::Pin;
 use core::task::Context;
 
 use crate::error::{Error, Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::Parser;
 use crate::stream::Positioned;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
 
 /// A parser for method [`expect`].
 ///
@@ -65,3 +72,38 @@ where
             })
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<P: Describe> Describe for Expect<P> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        describer.rule(self.expects.to_string(), |describer| {
+            self.inner.describe_with(describer)
+        })
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>> Generate<T> for Expect<P> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        self.inner.generate(gen)
+    }
+}
+
+impl<T, P: First<T>> First<T> for Expect<P> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.inner.first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        Some(self.expects.clone())
+    }
+}
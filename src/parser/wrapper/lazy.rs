@@ -2,6 +2,10 @@ use core::pin::Pin;
 use core::task::Context;
 
 use crate::error::PolledResult;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
 use crate::parser::iterable::IterableParser;
 use crate::parser::Parser;
 use crate::stream::Positioned;
@@ -83,3 +87,27 @@ where
             .poll_parse_next(input, cx, &mut state.inner)
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<F, P> Describe for Lazy<F>
+where
+    F: Fn() -> P,
+    P: Describe,
+{
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        describer.nested(|describer| (self.f)().describe_with(describer))
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, F, P> Generate<T> for Lazy<F>
+where
+    F: FnMut() -> P,
+    P: Generate<T>,
+{
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        gen.nested(|gen| (self.f)().generate(gen))
+    }
+}
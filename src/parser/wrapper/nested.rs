@@ -0,0 +1,125 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Expects, PolledResult};
+use crate::parser::first::First;
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::iterable::IterableParser;
+use crate::parser::Parser;
+use crate::stream::limit::Nesting;
+use crate::stream::Positioned;
+
+/// A parser for method [`nested`].
+///
+/// [`nested`]: crate::parser::ParserExt::nested
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Nested<P> {
+    inner: P,
+}
+
+impl<P> Nested<P> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: P) -> Self {
+        Self { inner }
+    }
+
+    /// Extracts the inner parser.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+/// The state for [`Nested`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NestedState<T> {
+    inner: T,
+    entered: bool,
+}
+
+impl<P, I> Parser<I> for Nested<P>
+where
+    P: Parser<I>,
+    I: Positioned + Nesting + ?Sized,
+{
+    type Output = P::Output;
+    type State = NestedState<P::State>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        if !state.entered {
+            input.as_mut().enter()?;
+            state.entered = true;
+        }
+
+        let res = ready!(self.inner.poll_parse(input.as_mut(), cx, &mut state.inner));
+        input.exit();
+        state.entered = false;
+        Poll::Ready(res)
+    }
+}
+
+impl<P, I> IterableParser<I> for Nested<P>
+where
+    P: IterableParser<I>,
+    I: Positioned + Nesting + ?Sized,
+{
+    type Item = P::Item;
+    type State = NestedState<P::State>;
+
+    fn poll_parse_next(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Option<Self::Item>, I> {
+        if !state.entered {
+            input.as_mut().enter()?;
+            state.entered = true;
+        }
+
+        let res = ready!(self
+            .inner
+            .poll_parse_next(input.as_mut(), cx, &mut state.inner));
+        input.exit();
+        state.entered = false;
+        Poll::Ready(res)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: Describe> Describe for Nested<P> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        self.inner.describe_with(describer)
+    }
+}
+
+impl<T, P: First<T>> First<T> for Nested<P> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.inner.first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        self.inner.first_expects()
+    }
+}
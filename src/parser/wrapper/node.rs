@@ -0,0 +1,102 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Expects, PolledResult};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::Parser;
+use crate::stream::cst::Cst;
+use crate::stream::Positioned;
+
+/// A parser for method [`node`].
+///
+/// [`node`]: crate::parser::ParserExt::node
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Node<P, K> {
+    inner: P,
+    kind: K,
+}
+
+impl<P, K> Node<P, K> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: P, kind: K) -> Self {
+        Self { inner, kind }
+    }
+
+    /// Extracts the inner parser.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+/// The state for [`Node`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NodeState<T> {
+    inner: T,
+    started: bool,
+}
+
+impl<P, K, I> Parser<I> for Node<P, K>
+where
+    P: Parser<I>,
+    K: Clone,
+    I: Positioned + Cst<Kind = K> + ?Sized,
+{
+    type Output = P::Output;
+    type State = NodeState<P::State>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        if !state.started {
+            input.as_mut().start_node(self.kind.clone());
+            state.started = true;
+        }
+
+        let res = ready!(self.inner.poll_parse(input.as_mut(), cx, &mut state.inner));
+        // Events of failed nodes are discarded by rewinding, otherwise the whole parse fails.
+        input.finish_node();
+        state.started = false;
+        Poll::Ready(res)
+    }
+}
+
+impl<P: Describe, K> Describe for Node<P, K> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        self.inner.describe_with(describer)
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>, K> Generate<T> for Node<P, K> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        self.inner.generate(gen)
+    }
+}
+
+impl<T, P: First<T>, K> First<T> for Node<P, K> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.inner.first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        self.inner.first_expects()
+    }
+}
@@ -0,0 +1,121 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use crate::error::{Expects, PolledResult, Status};
+use crate::parser::first::First;
+#[cfg(feature = "generate")]
+use crate::parser::generate::{Generate, GenerateError, Generator};
+#[cfg(feature = "alloc")]
+use crate::parser::grammar::{Describe, Describer, Grammar};
+use crate::parser::Parser;
+use crate::stream::trace::{TraceEvent, Traced};
+
+/// A parser for method [`trace`].
+///
+/// [`trace`]: crate::parser::ParserExt::trace
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trace<P> {
+    inner: P,
+    name: &'static str,
+}
+
+impl<P> Trace<P> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: P, name: &'static str) -> Self {
+        Self { inner, name }
+    }
+
+    /// Extracts the inner parser.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+crate::parser_state! {
+    pub struct TraceState<I, P: Parser> {
+        inner: P::State,
+        #[opt]
+        start: I::Locator,
+    }
+}
+
+impl<P, I> Parser<I> for Trace<P>
+where
+    P: Parser<I>,
+    I: Traced + ?Sized,
+{
+    type Output = P::Output;
+    type State = TraceState<I, P>;
+
+    fn poll_parse(
+        &mut self,
+        mut input: Pin<&mut I>,
+        cx: &mut Context<'_>,
+        state: &mut Self::State,
+    ) -> PolledResult<Self::Output, I> {
+        if state.start.is_none() {
+            state.start = Some(input.position());
+            let position = input.position();
+            input.as_mut().trace(TraceEvent::Enter {
+                name: self.name,
+                position,
+            });
+        }
+
+        let status = ready!(self
+            .inner
+            .poll_parse(input.as_mut(), cx, &mut state.inner)?);
+        let event = match &status {
+            Status::Success(_, _) => TraceEvent::Success {
+                name: self.name,
+                span: state.start()..input.position(),
+            },
+            Status::Failure(err, exclusive) => {
+                state.start = None;
+                TraceEvent::Failure {
+                    name: self.name,
+                    error: err,
+                    exclusive: *exclusive,
+                }
+            }
+        };
+        input.trace(event);
+        Poll::Ready(Ok(status))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: Describe> Describe for Trace<P> {
+    #[inline]
+    fn describe_with(&self, describer: &mut Describer) -> Grammar {
+        self.inner.describe_with(describer)
+    }
+}
+
+#[cfg(feature = "generate")]
+impl<T, P: Generate<T>> Generate<T> for Trace<P> {
+    #[inline]
+    fn generate(&mut self, gen: &mut Generator<'_, T>) -> Result<(), GenerateError> {
+        self.inner.generate(gen)
+    }
+}
+
+impl<T, P: First<T>> First<T> for Trace<P> {
+    #[inline]
+    fn first(&mut self, token: &T) -> bool {
+        self.inner.first(token)
+    }
+
+    #[inline]
+    fn nullable(&self) -> bool {
+        self.inner.nullable()
+    }
+
+    #[inline]
+    fn first_expects(&self) -> Option<Expects> {
+        self.inner.first_expects()
+    }
+}
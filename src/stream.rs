@@ -13,11 +13,24 @@ mod imp;
 pub use builder::*;
 pub use imp::*;
 
+pub mod bits;
+pub mod context;
+pub mod contiguous;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod cst;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod indent;
+pub mod limit;
+pub mod peek;
 pub mod position;
 #[cfg(feature = "alloc")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
 pub mod record;
 pub mod rewind;
+pub mod trace;
+pub mod window;
 
 pub use position::Positioned;
 pub use rewind::Rewind;
@@ -0,0 +1,263 @@
+//! Splits byte streams into bits.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::{ready, FusedStream, Stream, TryStream};
+use pin_project_lite::pin_project;
+
+use crate::stream::{Positioned, Rewind};
+
+/// A stream of bits which can be read byte by byte at byte boundaries.
+///
+/// It is used by [`aligned`] to switch back to byte-aligned parsing.
+///
+/// [`aligned`]: crate::parser::binary::aligned
+pub trait Align: TryStream<Ok = bool> {
+    /// Skips the remaining bits of the current byte, if any.
+    fn align(self: Pin<&mut Self>);
+
+    /// Attempts to pull out the next byte, after skipping the remaining bits of the current byte.
+    fn poll_next_byte(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<u8, Self::Error>>>;
+}
+
+/// The position of a bit, consists of the index of the byte and the offset in the byte.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitPosition {
+    pub byte: usize,
+    pub bit: u8,
+}
+
+pin_project! {
+    /// Wraps [`TryStream`] of bytes, implements [`TryStream`] of bits, [`Positioned`] and
+    /// [`Align`] trait.
+    ///
+    /// Bits are yielded from the most significant one by default.
+    ///
+    /// [`TryStream`]: futures_core::stream::TryStream
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct BitStream<S> {
+        #[pin]
+        inner: S,
+        current: Option<u8>,
+        bit: u8,
+        fetched: usize,
+        lsb_first: bool,
+    }
+}
+
+impl<S> From<S> for BitStream<S> {
+    #[inline]
+    fn from(inner: S) -> Self {
+        Self {
+            inner,
+            current: None,
+            bit: 0,
+            fetched: 0,
+            lsb_first: false,
+        }
+    }
+}
+
+impl<S> BitStream<S> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self::from(inner)
+    }
+
+    /// Yields bits from the least significant one, like DEFLATE.
+    #[inline]
+    pub fn lsb_first(mut self) -> Self {
+        self.lsb_first = true;
+        self
+    }
+
+    /// Extracts the original stream.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: TryStream<Ok = u8> + FusedStream> FusedStream for BitStream<S> {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.current.is_none() && self.inner.is_terminated()
+    }
+}
+
+impl<S: TryStream<Ok = u8>> Stream for BitStream<S> {
+    type Item = Result<bool, S::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let byte = match *this.current {
+            Some(byte) => byte,
+            None => match ready!(this.inner.try_poll_next(cx)) {
+                Some(Ok(byte)) => {
+                    *this.fetched += 1;
+                    *this.bit = 0;
+                    *this.current = Some(byte);
+                    byte
+                }
+                Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+                None => return Poll::Ready(None),
+            },
+        };
+
+        let shift = if *this.lsb_first {
+            *this.bit
+        } else {
+            7 - *this.bit
+        };
+        *this.bit += 1;
+        if *this.bit == 8 {
+            *this.current = None;
+        }
+        Poll::Ready(Some(Ok(byte >> shift & 1 == 1)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rem = match self.current {
+            Some(_) => usize::from(8 - self.bit),
+            None => 0,
+        };
+        let (lower, upper) = self.inner.size_hint();
+        (
+            lower.saturating_mul(8).saturating_add(rem),
+            upper.and_then(|u| u.checked_mul(8)?.checked_add(rem)),
+        )
+    }
+}
+
+impl<S: TryStream<Ok = u8>> Positioned for BitStream<S> {
+    type Locator = BitPosition;
+
+    #[inline]
+    fn position(&self) -> Self::Locator {
+        match self.current {
+            Some(_) => BitPosition {
+                byte: self.fetched - 1,
+                bit: self.bit,
+            },
+            None => BitPosition {
+                byte: self.fetched,
+                bit: 0,
+            },
+        }
+    }
+}
+
+impl<S: Rewind<Ok = u8>> Rewind for BitStream<S> {
+    type Marker = (S::Marker, Option<u8>, u8, usize);
+
+    #[inline]
+    fn mark(self: Pin<&mut Self>) -> Result<Self::Marker, Self::Error> {
+        let this = self.project();
+        Ok((this.inner.mark()?, *this.current, *this.bit, *this.fetched))
+    }
+
+    #[inline]
+    fn rewind(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        let this = self.project();
+        this.inner.rewind(marker.0)?;
+        *this.current = marker.1;
+        *this.bit = marker.2;
+        *this.fetched = marker.3;
+        Ok(())
+    }
+
+    #[inline]
+    fn drop_marker(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        self.project().inner.drop_marker(marker.0)
+    }
+}
+
+impl<S: TryStream<Ok = u8>> Align for BitStream<S> {
+    #[inline]
+    fn align(self: Pin<&mut Self>) {
+        *self.project().current = None;
+    }
+
+    fn poll_next_byte(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<u8, Self::Error>>> {
+        self.as_mut().align();
+        let this = self.project();
+        let res = ready!(this.inner.try_poll_next(cx));
+        if let Some(Ok(_)) = res {
+            *this.fetched += 1;
+        }
+        Poll::Ready(res)
+    }
+}
+
+/// A view of [`Align`] streams as streams of bytes, used by [`aligned`].
+///
+/// [`aligned`]: crate::parser::binary::aligned
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Bytes<S: ?Sized>(S);
+
+impl<S: ?Sized> Bytes<S> {
+    /// Views a pinned stream of bits as a stream of bytes.
+    #[inline]
+    pub fn from_pin(stream: Pin<&mut S>) -> Pin<&mut Self> {
+        // SAFETY: `Bytes` is a transparent wrapper and never moves the inner stream out.
+        unsafe { stream.map_unchecked_mut(|s| &mut *(s as *mut S as *mut Self)) }
+    }
+
+    #[inline]
+    fn inner(self: Pin<&mut Self>) -> Pin<&mut S> {
+        // SAFETY: the inner stream is structurally pinned.
+        unsafe { self.map_unchecked_mut(|b| &mut b.0) }
+    }
+}
+
+impl<S: Align + ?Sized> Stream for Bytes<S> {
+    type Item = Result<u8, S::Error>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner().poll_next_byte(cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.0.size_hint();
+        (lower / 8, upper.map(|u| u / 8))
+    }
+}
+
+impl<S: Align + Positioned + ?Sized> Positioned for Bytes<S> {
+    type Locator = S::Locator;
+
+    #[inline]
+    fn position(&self) -> Self::Locator {
+        self.0.position()
+    }
+}
+
+impl<S: Align + Rewind + ?Sized> Rewind for Bytes<S> {
+    type Marker = S::Marker;
+
+    #[inline]
+    fn mark(self: Pin<&mut Self>) -> Result<Self::Marker, Self::Error> {
+        self.inner().mark()
+    }
+
+    #[inline]
+    fn rewind(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        self.inner().rewind(marker)
+    }
+
+    #[inline]
+    fn drop_marker(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        self.inner().drop_marker(marker)
+    }
+}
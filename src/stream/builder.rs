@@ -2,9 +2,20 @@ use futures_core::{Stream, TryStream};
 #[cfg(feature = "std")]
 use futures_io::AsyncRead;
 
+use super::bits::BitStream;
+use super::context::ContextStream;
+use super::limit::Limited;
 use super::position::{Locator, PositionedStream};
+use super::trace::TraceStream;
+use super::window::WindowStream;
+#[cfg(feature = "alloc")]
+use super::Positioned;
 use super::{InfallibleStream, IteratorStream, SliceStream};
 
+#[cfg(feature = "alloc")]
+use super::cst::CstStream;
+#[cfg(feature = "alloc")]
+use super::indent::IndentStream;
 #[cfg(feature = "alloc")]
 use super::record::{ExtendRecorder, VecRecorder};
 #[cfg(feature = "alloc")]
@@ -216,6 +227,187 @@ pub trait StreamBuilder: TryStream {
     {
         ExtendRecorder::new(self, extend)
     }
+
+    /// Attaches a user context to a stream, which can be accessed by parsers like [`map_ctx`].
+    ///
+    /// The context is restored when the stream is rewound, so it should be attached last (after
+    /// [`buffered_rewind`] and so on).
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use somen::prelude::*;
+    /// use std::collections::HashSet;
+    ///
+    /// let name = || is(|c: &char| c.is_ascii_lowercase()).repeat(1..).collect::<String>();
+    /// // Declares a type name, like `typedef` in C.
+    /// let typedef = tag("type ")
+    ///     .prefix(name())
+    ///     .map_ctx(|name, types: &mut HashSet<String>| types.insert(name))
+    ///     .discard();
+    /// let decl = name()
+    ///     .satisfy_ctx(|name, types: &mut HashSet<String>| types.contains(name))
+    ///     .skip(token(' '))
+    ///     .and(name());
+    /// let mut parser = typedef.skip(token(';')).prefix(decl);
+    ///
+    /// let mut stream = stream::from_iter("type foo;foo x".chars())
+    ///     .buffered_rewind()
+    ///     .with_context(HashSet::new());
+    /// assert_eq!(
+    ///     parser.parse(&mut stream).await,
+    ///     Ok((String::from("foo"), String::from("x"))),
+    /// );
+    ///
+    /// let mut stream = stream::from_iter("type foo;bar x".chars())
+    ///     .buffered_rewind()
+    ///     .with_context(HashSet::new());
+    /// assert!(parser.parse(&mut stream).await.is_err());
+    /// # });
+    /// ```
+    ///
+    /// [`map_ctx`]: crate::parser::ParserExt::map_ctx
+    /// [`buffered_rewind`]: Self::buffered_rewind
+    #[inline]
+    fn with_context<C>(self, ctx: C) -> ContextStream<Self, C>
+    where
+        Self: Sized,
+    {
+        ContextStream::new(self, ctx)
+    }
+
+    /// Limits the nesting depth of parsers and the number of consumed tokens.
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use somen::prelude::*;
+    /// use somen::call;
+    /// use somen::error::ParseError;
+    /// use somen::stream::limit::{LimitError, Nesting};
+    ///
+    /// fn list<'a, I: Input<Ok = char> + Nesting + 'a>() -> impl Parser<I, Output = usize> + 'a {
+    ///     call!(list)
+    ///         .nested()
+    ///         .between(token('['), token(']'))
+    ///         .map(|depth| depth + 1)
+    ///         .or(value(0))
+    /// }
+    ///
+    /// let mut stream = stream::from_iter("[[[]]]".chars())
+    ///     .buffered_rewind()
+    ///     .limited()
+    ///     .max_depth(8);
+    /// assert_eq!(list().parse(&mut stream).await, Ok(3));
+    ///
+    /// let mut stream = stream::from_iter(std::iter::repeat('[').take(100))
+    ///     .buffered_rewind()
+    ///     .limited()
+    ///     .max_depth(8);
+    /// assert_eq!(list().parse(&mut stream).await, Err(ParseError::Stream(LimitError::Depth)));
+    ///
+    /// let mut stream = stream::from_iter("[[[]]]".chars())
+    ///     .buffered_rewind()
+    ///     .limited()
+    ///     .fuel(4);
+    /// assert_eq!(list().parse(&mut stream).await, Err(ParseError::Stream(LimitError::Fuel)));
+    /// # });
+    /// ```
+    #[inline]
+    fn limited(self) -> Limited<Self>
+    where
+        Self: Sized,
+    {
+        Limited::new(self)
+    }
+
+    /// Implements [`Window`] to a stream, which is required by framing parsers like
+    /// [`length_prefixed`].
+    ///
+    /// [`Window`]: crate::stream::window::Window
+    /// [`length_prefixed`]: crate::parser::length_prefixed
+    #[inline]
+    fn windowed(self) -> WindowStream<Self>
+    where
+        Self: Sized,
+    {
+        WindowStream::new(self)
+    }
+
+    /// Splits a stream of bytes into bits, from the most significant one.
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use somen::stream::StreamBuilder;
+    /// use futures::stream::TryStreamExt;
+    ///
+    /// let stream = somen::stream::from_slice(b"\xa0").bits();
+    /// let bits: Vec<bool> = stream.try_collect().await.unwrap();
+    /// assert_eq!(bits, [true, false, true, false, false, false, false, false]);
+    /// # });
+    /// ```
+    #[inline]
+    fn bits(self) -> BitStream<Self>
+    where
+        Self: TryStream<Ok = u8> + Sized,
+    {
+        BitStream::new(self)
+    }
+
+    /// Sends events of parsers wrapped by [`trace`] and rewinding operations to `sink`.
+    ///
+    /// [`trace`]: crate::parser::ParserExt::trace
+    #[inline]
+    fn traced<T>(self, sink: T) -> TraceStream<Self, T>
+    where
+        Self: Sized,
+    {
+        TraceStream::new(self, sink)
+    }
+
+    /// Records consumed tokens and nodes marked by [`node`], to build a concrete syntax tree.
+    ///
+    /// [`node`]: crate::parser::ParserExt::node
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn cst<K>(self) -> CstStream<Self, K>
+    where
+        Self: Sized,
+    {
+        CstStream::new(self)
+    }
+
+    /// Injects tokens indicate the increase and decrease of indentation levels.
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use somen::stream::StreamBuilder;
+    /// use somen::stream::indent::IndentToken::{self, Char, Dedent, Indent};
+    /// use futures::stream::TryStreamExt;
+    ///
+    /// let stream = somen::stream::from_iter("a\n b\nc".chars())
+    ///     .positioned::<usize>()
+    ///     .indent_tokens();
+    ///
+    /// let tokens: Vec<IndentToken> = stream.try_collect().await.unwrap();
+    /// assert_eq!(
+    ///     tokens,
+    ///     [Char('a'), Char('\n'), Char(' '), Indent, Char('b'), Char('\n'), Dedent, Char('c')],
+    /// );
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn indent_tokens(self) -> IndentStream<Self>
+    where
+        Self: Positioned<Ok = char> + Sized,
+    {
+        IndentStream::from(self)
+    }
 }
 
 impl<T: TryStream> StreamBuilder for T {}
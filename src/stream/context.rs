@@ -0,0 +1,156 @@
+//! Carries user contexts along with streams.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::{FusedStream, Stream, TryStream};
+use pin_project_lite::pin_project;
+
+use crate::stream::contiguous::Contiguous;
+use crate::stream::limit::Nesting;
+use crate::stream::peek::Peek;
+use crate::stream::{Positioned, Rewind};
+
+/// A stream that carries a mutable user context, like symbol tables or configurations.
+pub trait Contextual {
+    /// The type of the context.
+    type Ctx;
+
+    /// Returns a reference to the context.
+    fn ctx(&self) -> &Self::Ctx;
+
+    /// Returns a mutable reference to the context.
+    fn ctx_mut(self: Pin<&mut Self>) -> &mut Self::Ctx;
+}
+
+pin_project! {
+    /// Wraps a stream, implements [`Contextual`] trait.
+    ///
+    /// A snapshot of the context is stored in each marker, so modifications on the context are
+    /// undone when the stream is rewound. Contexts which are cheap to clone (e.g. persistent data
+    /// structures) are recommended if the grammar backtracks a lot.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ContextStream<S, C> {
+        #[pin]
+        inner: S,
+        ctx: C,
+    }
+}
+
+impl<S, C> ContextStream<S, C> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: S, ctx: C) -> Self {
+        Self { inner, ctx }
+    }
+
+    /// Extracts the original stream and the context.
+    #[inline]
+    pub fn into_inner(self) -> (S, C) {
+        (self.inner, self.ctx)
+    }
+}
+
+impl<S: TryStream + FusedStream, C> FusedStream for ContextStream<S, C> {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<S: TryStream, C> Stream for ContextStream<S, C> {
+    type Item = Result<S::Ok, S::Error>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.try_poll_next(cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S: Positioned, C> Positioned for ContextStream<S, C> {
+    type Locator = S::Locator;
+
+    #[inline]
+    fn position(&self) -> Self::Locator {
+        self.inner.position()
+    }
+}
+
+impl<S: Rewind, C: Clone> Rewind for ContextStream<S, C> {
+    type Marker = (S::Marker, C);
+
+    #[inline]
+    fn mark(self: Pin<&mut Self>) -> Result<Self::Marker, Self::Error> {
+        let this = self.project();
+        Ok((this.inner.mark()?, this.ctx.clone()))
+    }
+
+    #[inline]
+    fn rewind(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        let this = self.project();
+        this.inner.rewind(marker.0)?;
+        *this.ctx = marker.1;
+        Ok(())
+    }
+
+    #[inline]
+    fn drop_marker(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        self.project().inner.drop_marker(marker.0)
+    }
+}
+
+impl<'a, S: Contiguous<'a>, C> Contiguous<'a> for ContextStream<S, C>
+where
+    S::Ok: 'a,
+{
+    #[inline]
+    fn remaining(&self) -> &'a [Self::Ok] {
+        self.inner.remaining()
+    }
+
+    #[inline]
+    fn advance(self: Pin<&mut Self>, n: usize) {
+        self.project().inner.advance(n)
+    }
+}
+
+impl<S: Peek, C> Peek for ContextStream<S, C> {
+    #[inline]
+    fn poll_peek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<Result<&[Self::Ok], Self::Error>> {
+        self.project().inner.poll_peek(cx, n)
+    }
+}
+
+impl<S: Nesting, C> Nesting for ContextStream<S, C> {
+    #[inline]
+    fn enter(self: Pin<&mut Self>) -> Result<(), Self::Error> {
+        self.project().inner.enter()
+    }
+
+    #[inline]
+    fn exit(self: Pin<&mut Self>) {
+        self.project().inner.exit()
+    }
+}
+
+impl<S, C> Contextual for ContextStream<S, C> {
+    type Ctx = C;
+
+    #[inline]
+    fn ctx(&self) -> &Self::Ctx {
+        &self.ctx
+    }
+
+    #[inline]
+    fn ctx_mut(self: Pin<&mut Self>) -> &mut Self::Ctx {
+        self.project().ctx
+    }
+}
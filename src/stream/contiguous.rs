@@ -0,0 +1,41 @@
+//! Streams whose tokens are stored contiguously in memory.
+
+use core::pin::Pin;
+
+use crate::stream::Positioned;
+
+/// A stream that exposes its remaining tokens as a slice, like [`SliceStream`].
+///
+/// Parsers in [`parser::slice`] scan the slice at once instead of polling tokens one by one, and
+/// return the slices borrowed from the input.
+///
+/// # Examples
+/// ```
+/// use core::pin::Pin;
+/// use somen::prelude::*;
+/// use somen::stream::contiguous::Contiguous;
+///
+/// let mut stream = stream::from_slice(b"abc");
+/// assert_eq!(stream.remaining(), b"abc");
+///
+/// Pin::new(&mut stream).advance(2);
+/// assert_eq!(stream.remaining(), b"c");
+/// assert_eq!(stream.position(), 2);
+/// ```
+///
+/// [`SliceStream`]: crate::stream::SliceStream
+/// [`parser::slice`]: crate::parser::slice
+pub trait Contiguous<'a>: Positioned
+where
+    Self::Ok: 'a,
+{
+    /// Returns the remaining tokens.
+    fn remaining(&self) -> &'a [Self::Ok];
+
+    /// Consumes `n` tokens.
+    ///
+    /// `n` must not be greater than the length of [`remaining`].
+    ///
+    /// [`remaining`]: Self::remaining
+    fn advance(self: Pin<&mut Self>, n: usize);
+}
@@ -0,0 +1,219 @@
+//! Builds lossless concrete syntax trees.
+
+mod reparse;
+mod tree;
+
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::{ready, FusedStream, Stream, TryStream};
+use pin_project_lite::pin_project;
+
+use crate::stream::context::Contextual;
+use crate::stream::limit::Nesting;
+use crate::stream::peek::Peek;
+use crate::stream::{Positioned, Rewind};
+pub use reparse::{Edit, ReparseStream};
+pub use tree::{SyntaxElement, SyntaxNode, SyntaxToken};
+
+/// A stream that records syntax nodes.
+///
+/// Parsers wrapped by [`node`] call [`start_node`] before parsing and [`finish_node`] after
+/// parsing.
+///
+/// [`node`]: crate::parser::ParserExt::node
+/// [`start_node`]: Self::start_node
+/// [`finish_node`]: Self::finish_node
+pub trait Cst: TryStream {
+    /// The type of node kinds.
+    type Kind;
+
+    /// Starts a new node, following tokens and nodes are its children.
+    fn start_node(self: Pin<&mut Self>, kind: Self::Kind);
+
+    /// Finishes the last started node.
+    fn finish_node(self: Pin<&mut Self>);
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Event<K, T> {
+    Start(K),
+    Finish,
+    Token(T),
+}
+
+pin_project! {
+    /// Wraps [`TryStream`], implements [`Cst`] trait by recording all the consumed tokens and
+    /// node events, in the style of green trees of `rowan`.
+    ///
+    /// Events are discarded when the stream is rewound, so nodes of abandoned branches never
+    /// appear in the tree.
+    ///
+    /// [`TryStream`]: futures_core::stream::TryStream
+    #[derive(Debug)]
+    pub struct CstStream<S: TryStream, K> {
+        #[pin]
+        inner: S,
+        events: Vec<Event<K, S::Ok>>,
+    }
+}
+
+impl<S: TryStream, K> From<S> for CstStream<S, K> {
+    #[inline]
+    fn from(inner: S) -> Self {
+        Self {
+            inner,
+            events: Vec::new(),
+        }
+    }
+}
+
+impl<S: TryStream, K> CstStream<S, K> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self::from(inner)
+    }
+
+    /// Builds a tree under the root node of `kind`, unfinished nodes are finished at the end.
+    ///
+    /// The spans are the indices of tokens, counted from the creation of this stream.
+    pub fn into_tree(self, kind: K) -> SyntaxNode<K, S::Ok> {
+        tree::build(kind, self.events)
+    }
+
+    /// Extracts the original stream.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: TryStream + FusedStream, K> FusedStream for CstStream<S, K>
+where
+    S::Ok: Clone,
+{
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<S: TryStream, K> Stream for CstStream<S, K>
+where
+    S::Ok: Clone,
+{
+    type Item = Result<S::Ok, S::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let res = ready!(this.inner.try_poll_next(cx));
+        if let Some(Ok(token)) = &res {
+            this.events.push(Event::Token(token.clone()));
+        }
+        Poll::Ready(res)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S: Positioned, K> Positioned for CstStream<S, K>
+where
+    S::Ok: Clone,
+{
+    type Locator = S::Locator;
+
+    #[inline]
+    fn position(&self) -> Self::Locator {
+        self.inner.position()
+    }
+}
+
+impl<S: Rewind, K> Rewind for CstStream<S, K>
+where
+    S::Ok: Clone,
+{
+    type Marker = (S::Marker, usize);
+
+    #[inline]
+    fn mark(self: Pin<&mut Self>) -> Result<Self::Marker, Self::Error> {
+        let this = self.project();
+        Ok((this.inner.mark()?, this.events.len()))
+    }
+
+    #[inline]
+    fn rewind(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        let this = self.project();
+        this.inner.rewind(marker.0)?;
+        this.events.truncate(marker.1);
+        Ok(())
+    }
+
+    #[inline]
+    fn drop_marker(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        self.project().inner.drop_marker(marker.0)
+    }
+}
+
+impl<S: Peek, K> Peek for CstStream<S, K>
+where
+    S::Ok: Clone,
+{
+    #[inline]
+    fn poll_peek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<Result<&[Self::Ok], Self::Error>> {
+        self.project().inner.poll_peek(cx, n)
+    }
+}
+
+impl<S: TryStream, K> Cst for CstStream<S, K>
+where
+    S::Ok: Clone,
+{
+    type Kind = K;
+
+    #[inline]
+    fn start_node(self: Pin<&mut Self>, kind: Self::Kind) {
+        self.project().events.push(Event::Start(kind));
+    }
+
+    #[inline]
+    fn finish_node(self: Pin<&mut Self>) {
+        self.project().events.push(Event::Finish);
+    }
+}
+
+impl<S: Contextual + TryStream, K> Contextual for CstStream<S, K> {
+    type Ctx = S::Ctx;
+
+    #[inline]
+    fn ctx(&self) -> &Self::Ctx {
+        self.inner.ctx()
+    }
+
+    #[inline]
+    fn ctx_mut(self: Pin<&mut Self>) -> &mut Self::Ctx {
+        self.project().inner.ctx_mut()
+    }
+}
+
+impl<S: Nesting, K> Nesting for CstStream<S, K>
+where
+    S::Ok: Clone,
+{
+    #[inline]
+    fn enter(self: Pin<&mut Self>) -> Result<(), Self::Error> {
+        self.project().inner.enter()
+    }
+
+    #[inline]
+    fn exit(self: Pin<&mut Self>) {
+        self.project().inner.exit()
+    }
+}
@@ -0,0 +1,200 @@
+use alloc::vec::{self, Vec};
+use core::ops::Range;
+
+use super::{CstStream, SyntaxElement, SyntaxNode};
+use crate::parser::{eof, Parser, ParserExt};
+use crate::stream::rewind::BufferedRewinder;
+use crate::stream::{InfallibleStream, IteratorStream};
+
+/// The input type for parsers used by [`SyntaxNode::reparse`].
+pub type ReparseStream<T, K> =
+    CstStream<BufferedRewinder<InfallibleStream<IteratorStream<vec::IntoIter<T>>>>, K>;
+
+/// An edit on the source, replaces the tokens in `range` with `text`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Edit<T> {
+    pub range: Range<usize>,
+    pub text: Vec<T>,
+}
+
+impl<K: Clone + PartialEq, T: Clone> SyntaxNode<K, T> {
+    /// Applies an edit to the tree, by re-parsing only the smallest node enclosing the edit.
+    ///
+    /// Nodes containing the edit are tried from the innermost one, with the parser returned by
+    /// `parser` for its kind (nodes of kinds returning [`None`] are skipped). The parser must
+    /// produce exactly one node of the same kind consuming all the edited tokens, usually by
+    /// the same [`node`] parser used to build the tree. The node is replaced in place, and the
+    /// other subtrees are reused as is, with their spans shifted.
+    ///
+    /// Returns `false` if no nodes can be re-parsed, the tree is left untouched then and the
+    /// whole source should be parsed again. Note that the parsers must not depend on tokens out
+    /// of the node, otherwise the result may differ from a full parse.
+    ///
+    /// # Panics
+    /// Panics if the edit is out of the span of the tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use somen::prelude::*;
+    /// use somen::stream::cst::{Cst, Edit};
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// enum Kind { Root, List, Word }
+    ///
+    /// fn word<I: Input<Ok = char> + Cst<Kind = Kind>>() -> impl Parser<I, Output = ()> {
+    ///     is(|c: &char| c.is_ascii_lowercase()).repeat(1..).discard().node(Kind::Word)
+    /// }
+    /// fn list<I: Input<Ok = char> + Cst<Kind = Kind>>() -> impl Parser<I, Output = ()> {
+    ///     word()
+    ///         .sep_by(token(','), ..)
+    ///         .discard()
+    ///         .between(token('['), token(']'))
+    ///         .node(Kind::List)
+    /// }
+    ///
+    /// let mut stream = stream::from_iter("[ab,cd][ef]".chars().collect::<Vec<_>>())
+    ///     .buffered_rewind()
+    ///     .cst();
+    /// list().and(list()).parse(&mut stream).await.unwrap();
+    /// let mut tree = stream.into_tree(Kind::Root);
+    ///
+    /// // Inserts "xy" into "cd", only the word is re-parsed.
+    /// let edit = Edit { range: 5..5, text: vec!['x', 'y'] };
+    /// let reparsed = tree
+    ///     .reparse(&edit, |kind| match kind {
+    ///         Kind::Word => Some(word().no_state().boxed()),
+    ///         Kind::List => Some(list().no_state().boxed()),
+    ///         Kind::Root => None,
+    ///     })
+    ///     .await;
+    /// assert!(reparsed);
+    /// assert_eq!(tree.text::<String>(), "[ab,cxyd][ef]");
+    /// assert_eq!(tree.span, 0..13);
+    /// assert_eq!(tree.nodes().nth(1).unwrap().span, 9..13);
+    ///
+    /// // Inserting a comma splits the word, so the enclosing list is re-parsed instead.
+    /// let edit = Edit { range: 2..2, text: vec![','] };
+    /// assert!(tree.reparse(&edit, |kind| match kind {
+    ///     Kind::Word => Some(word().no_state().boxed()),
+    ///     Kind::List => Some(list().no_state().boxed()),
+    ///     Kind::Root => None,
+    /// }).await);
+    /// assert_eq!(tree.nodes().next().unwrap().nodes().count(), 3);
+    /// # });
+    /// ```
+    ///
+    /// [`node`]: crate::parser::ParserExt::node
+    pub async fn reparse<F, P>(&mut self, edit: &Edit<T>, mut parser: F) -> bool
+    where
+        F: FnMut(&K) -> Option<P>,
+        P: Parser<ReparseStream<T, K>>,
+    {
+        assert!(
+            self.span.start <= edit.range.start
+                && edit.range.start <= edit.range.end
+                && edit.range.end <= self.span.end,
+            "the edit is out of the tree"
+        );
+
+        // Finds the path to the innermost node enclosing the edit.
+        let mut path = Vec::new();
+        let mut node = &*self;
+        while let Some((idx, child)) = node.children.iter().enumerate().find_map(|(i, c)| match c {
+            SyntaxElement::Node(n)
+                if n.span.start <= edit.range.start && edit.range.end <= n.span.end =>
+            {
+                Some((i, n))
+            }
+            _ => None,
+        }) {
+            path.push(idx);
+            node = child;
+        }
+
+        while !path.is_empty() {
+            let target = self.get(&path);
+            if let Some(mut p) = parser(&target.kind) {
+                if let Some(new) = target.reparse_node(edit, &mut p).await {
+                    self.replace(&path, new, delta(edit));
+                    return true;
+                }
+            }
+            path.pop();
+        }
+        false
+    }
+
+    async fn reparse_node<P>(&self, edit: &Edit<T>, parser: &mut P) -> Option<Self>
+    where
+        P: Parser<ReparseStream<T, K>>,
+    {
+        let mut tokens: Vec<T> = self.text();
+        let start = self.span.start;
+        tokens.splice(
+            edit.range.start - start..edit.range.end - start,
+            edit.text.iter().cloned(),
+        );
+        let len = tokens.len();
+
+        let mut stream = CstStream::new(BufferedRewinder::from(InfallibleStream::from(
+            IteratorStream::from(tokens.into_iter()),
+        )));
+        parser.parse(&mut stream).await.ok()?;
+        eof().parse(&mut stream).await.ok()?;
+
+        let mut tree = stream.into_tree(self.kind.clone());
+        match tree.children.pop() {
+            Some(SyntaxElement::Node(mut node))
+                if tree.children.is_empty() && node.kind == self.kind && node.span == (0..len) =>
+            {
+                shift(&mut node, start as isize);
+                Some(node)
+            }
+            _ => None,
+        }
+    }
+
+    fn get(&self, path: &[usize]) -> &Self {
+        path.iter()
+            .fold(self, |node, &idx| match &node.children[idx] {
+                SyntaxElement::Node(node) => node,
+                SyntaxElement::Token(_) => unreachable!(),
+            })
+    }
+
+    fn replace(&mut self, path: &[usize], new: Self, delta: isize) {
+        self.span.end = self.span.end.wrapping_add_signed(delta);
+        let (idx, rest) = path.split_first().unwrap();
+        for child in &mut self.children[idx + 1..] {
+            match child {
+                SyntaxElement::Node(node) => shift(node, delta),
+                SyntaxElement::Token(token) => shift_span(&mut token.span, delta),
+            }
+        }
+        match &mut self.children[*idx] {
+            SyntaxElement::Node(node) if rest.is_empty() => *node = new,
+            SyntaxElement::Node(node) => node.replace(rest, new, delta),
+            SyntaxElement::Token(_) => unreachable!(),
+        }
+    }
+}
+
+fn delta<T>(edit: &Edit<T>) -> isize {
+    edit.text.len() as isize - (edit.range.end - edit.range.start) as isize
+}
+
+fn shift_span(span: &mut Range<usize>, delta: isize) {
+    span.start = span.start.wrapping_add_signed(delta);
+    span.end = span.end.wrapping_add_signed(delta);
+}
+
+fn shift<K, T>(node: &mut SyntaxNode<K, T>, delta: isize) {
+    shift_span(&mut node.span, delta);
+    for child in &mut node.children {
+        match child {
+            SyntaxElement::Node(node) => shift(node, delta),
+            SyntaxElement::Token(token) => shift_span(&mut token.span, delta),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use super::Event;
+
+/// A node of concrete syntax trees.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyntaxNode<K, T> {
+    pub kind: K,
+    pub span: Range<usize>,
+    pub children: Vec<SyntaxElement<K, T>>,
+}
+
+/// A sequence of tokens in a node, not divided by any child nodes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyntaxToken<T> {
+    pub tokens: Vec<T>,
+    pub span: Range<usize>,
+}
+
+/// A child of [`SyntaxNode`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SyntaxElement<K, T> {
+    Node(SyntaxNode<K, T>),
+    Token(SyntaxToken<T>),
+}
+
+impl<K, T> SyntaxNode<K, T> {
+    /// Returns an iterator over the child nodes.
+    pub fn nodes(&self) -> impl Iterator<Item = &SyntaxNode<K, T>> {
+        self.children.iter().filter_map(|child| match child {
+            SyntaxElement::Node(node) => Some(node),
+            SyntaxElement::Token(_) => None,
+        })
+    }
+
+    /// Collects all the tokens in the node, which are the same as the source.
+    pub fn text<E: Default + Extend<T>>(&self) -> E
+    where
+        T: Clone,
+    {
+        let mut text = E::default();
+        self.write_text(&mut text);
+        text
+    }
+
+    fn write_text<E: Extend<T>>(&self, text: &mut E)
+    where
+        T: Clone,
+    {
+        for child in &self.children {
+            match child {
+                SyntaxElement::Node(node) => node.write_text(text),
+                SyntaxElement::Token(token) => text.extend(token.tokens.iter().cloned()),
+            }
+        }
+    }
+}
+
+/// Builds a tree from the events.
+pub(super) fn build<K, T>(kind: K, events: Vec<Event<K, T>>) -> SyntaxNode<K, T> {
+    let mut stack = Vec::from([SyntaxNode {
+        kind,
+        span: 0..0,
+        children: Vec::new(),
+    }]);
+    let mut offset = 0;
+
+    for event in events {
+        match event {
+            Event::Start(kind) => stack.push(SyntaxNode {
+                kind,
+                span: offset..offset,
+                children: Vec::new(),
+            }),
+            // Ignores the unbalanced one, the root node must not be finished.
+            Event::Finish if stack.len() == 1 => {}
+            Event::Finish => finish(&mut stack, offset),
+            Event::Token(token) => {
+                let children = &mut stack.last_mut().unwrap().children;
+                match children.last_mut() {
+                    Some(SyntaxElement::Token(last)) => {
+                        last.tokens.push(token);
+                        last.span.end += 1;
+                    }
+                    _ => children.push(SyntaxElement::Token(SyntaxToken {
+                        tokens: Vec::from([token]),
+                        span: offset..offset + 1,
+                    })),
+                }
+                offset += 1;
+            }
+        }
+    }
+
+    while stack.len() > 1 {
+        finish(&mut stack, offset);
+    }
+    let mut root = stack.pop().unwrap();
+    root.span.end = offset;
+    root
+}
+
+fn finish<K, T>(stack: &mut Vec<SyntaxNode<K, T>>, offset: usize) {
+    let mut node = stack.pop().unwrap();
+    node.span.end = offset;
+    stack
+        .last_mut()
+        .unwrap()
+        .children
+        .push(SyntaxElement::Node(node));
+}
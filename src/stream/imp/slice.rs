@@ -4,11 +4,29 @@ use core::task::{Context, Poll};
 use futures_core::Stream;
 use pin_project_lite::pin_project;
 
+use crate::stream::contiguous::Contiguous;
+use crate::stream::peek::Peek;
 use crate::stream::{Positioned, Rewind};
 
 pin_project! {
     /// Wraps slices, implements [`TryStream`], [`Positioned`] and [`Rewind`] trait.
     ///
+    /// Reading past the end of the slice doesn't advance the position.
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::TryStreamExt;
+    /// use somen::prelude::*;
+    ///
+    /// let mut stream = stream::from_slice(b"a");
+    /// assert_eq!(stream.try_next().await, Ok(Some(b'a')));
+    /// assert_eq!(stream.try_next().await, Ok(None));
+    /// assert_eq!(stream.try_next().await, Ok(None));
+    /// assert_eq!(stream.position(), 1);
+    /// # });
+    /// ```
+    ///
     /// [`TryStream`]: futures_core::stream::TryStream
     #[derive(Clone, Debug, PartialEq, Eq)]
     pub struct SliceStream<'a, T> {
@@ -37,7 +55,9 @@ impl<T: Clone> Stream for SliceStream<'_, T> {
     fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let this = self.project();
         let res = this.slice.get(*this.position);
-        *this.position += 1;
+        if res.is_some() {
+            *this.position += 1;
+        }
         Poll::Ready(res.cloned().map(Ok))
     }
 
@@ -70,3 +90,28 @@ impl<T: Clone> Rewind for SliceStream<'_, T> {
         Ok(())
     }
 }
+
+impl<T: Clone> Peek for SliceStream<'_, T> {
+    #[inline]
+    fn poll_peek(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<Result<&[Self::Ok], Self::Error>> {
+        let rest = &self.slice[self.position..];
+        Poll::Ready(Ok(&rest[..n.min(rest.len())]))
+    }
+}
+
+impl<'a, T: Clone> Contiguous<'a> for SliceStream<'a, T> {
+    #[inline]
+    fn remaining(&self) -> &'a [T] {
+        &self.slice[self.position..]
+    }
+
+    #[inline]
+    fn advance(self: Pin<&mut Self>, n: usize) {
+        let this = self.project();
+        *this.position = (*this.position + n).min(this.slice.len());
+    }
+}
@@ -0,0 +1,286 @@
+//! Injects indentation tokens to streams.
+
+mod error;
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::{ready, FusedStream, Stream};
+use pin_project_lite::pin_project;
+
+use crate::stream::peek::Peek;
+use crate::stream::{Positioned, Rewind};
+pub use error::IndentError;
+
+/// Tokens produced by [`IndentStream`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IndentToken {
+    /// A character of the original stream.
+    Char(char),
+    /// Emitted before the first character of a line indented deeper than the previous one.
+    Indent,
+    /// Emitted for each indentation level closed, before the first character of the line or at
+    /// the end of the stream.
+    Dedent,
+}
+
+/// The indentation state of [`IndentStream`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Layout<L> {
+    levels: Vec<usize>,
+    width: usize,
+    line_start: bool,
+    indent: bool,
+    dedents: usize,
+    held: Option<(char, L)>,
+    // Tokens read ahead by `poll_peek`, and their positions.
+    peeked: VecDeque<IndentToken>,
+    peeked_pos: VecDeque<L>,
+}
+
+impl<L> Default for Layout<L> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            levels: Vec::new(),
+            width: 0,
+            line_start: true,
+            indent: false,
+            dedents: 0,
+            held: None,
+            peeked: VecDeque::new(),
+            peeked_pos: VecDeque::new(),
+        }
+    }
+}
+
+pin_project! {
+    /// Wraps a stream of [`char`]s, injects [`IndentToken::Indent`] and [`IndentToken::Dedent`]
+    /// like the tokenizer of Python.
+    ///
+    /// Each space or tab at the start of lines counts as one column, and blank lines never change
+    /// the indentation level. Dedenting to a level which was never opened is an error.
+    #[derive(Debug)]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    pub struct IndentStream<S: Positioned> {
+        #[pin]
+        inner: S,
+        layout: Layout<S::Locator>,
+    }
+}
+
+impl<S: Positioned> From<S> for IndentStream<S> {
+    #[inline]
+    fn from(inner: S) -> Self {
+        Self {
+            inner,
+            layout: Layout::default(),
+        }
+    }
+}
+
+impl<S: Positioned> IndentStream<S> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self::from(inner)
+    }
+
+    /// Extracts the original stream.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: Positioned<Ok = char> + FusedStream> FusedStream for IndentStream<S> {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated() && self.layout.levels.is_empty() && self.layout.peeked.is_empty()
+    }
+}
+
+impl<S: Positioned<Ok = char>> Stream for IndentStream<S> {
+    type Item = Result<IndentToken, IndentError<S::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if let Some(token) = this.layout.peeked.pop_front() {
+            this.layout.peeked_pos.pop_front();
+            return Poll::Ready(Some(Ok(token)));
+        }
+        poll_token(this.inner, this.layout, cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = self.inner.size_hint().0;
+        (lower.saturating_add(self.layout.peeked.len()), None)
+    }
+}
+
+// Reads the next token from the inner stream, regardless of peeked tokens.
+fn poll_token<S: Positioned<Ok = char>>(
+    mut inner: Pin<&mut S>,
+    layout: &mut Layout<S::Locator>,
+    cx: &mut Context<'_>,
+) -> Poll<Option<Result<IndentToken, IndentError<S::Error>>>> {
+    loop {
+        if layout.dedents > 0 {
+            layout.dedents -= 1;
+            return Poll::Ready(Some(Ok(IndentToken::Dedent)));
+        }
+        if layout.indent {
+            layout.indent = false;
+            return Poll::Ready(Some(Ok(IndentToken::Indent)));
+        }
+        if let Some((c, _)) = layout.held.take() {
+            return Poll::Ready(Some(Ok(IndentToken::Char(c))));
+        }
+
+        let pos = layout.line_start.then(|| inner.position());
+        let c = match ready!(inner.as_mut().try_poll_next(cx)) {
+            Some(Ok(c)) => c,
+            Some(Err(e)) => return Poll::Ready(Some(Err(IndentError::Stream(e)))),
+            // Close all the remaining levels.
+            None => {
+                return Poll::Ready(layout.levels.pop().map(|_| Ok(IndentToken::Dedent)));
+            }
+        };
+
+        if !layout.line_start {
+            if c == '\n' {
+                layout.line_start = true;
+                layout.width = 0;
+            }
+            return Poll::Ready(Some(Ok(IndentToken::Char(c))));
+        }
+
+        match c {
+            ' ' | '\t' => layout.width += 1,
+            // Blank lines are ignored.
+            '\n' => layout.width = 0,
+            '\r' => {}
+            _ => {
+                layout.line_start = false;
+                let current = layout.levels.last().copied().unwrap_or(0);
+                if layout.width > current {
+                    layout.levels.push(layout.width);
+                    layout.indent = true;
+                } else {
+                    while layout.width < layout.levels.last().copied().unwrap_or(0) {
+                        layout.levels.pop();
+                        layout.dedents += 1;
+                    }
+                    if layout.width != layout.levels.last().copied().unwrap_or(0) {
+                        layout.dedents = 0;
+                        return Poll::Ready(Some(Err(IndentError::Dedent)));
+                    }
+                }
+                if layout.indent || layout.dedents > 0 {
+                    // Synthetic tokens are placed before the character.
+                    layout.held = Some((c, pos.unwrap()));
+                    continue;
+                }
+            }
+        }
+        return Poll::Ready(Some(Ok(IndentToken::Char(c))));
+    }
+}
+
+// The position of the next token read by `poll_token`.
+fn position<S: Positioned>(inner: &S, layout: &Layout<S::Locator>) -> S::Locator
+where
+    S::Locator: Clone,
+{
+    match &layout.held {
+        Some((_, pos)) => pos.clone(),
+        None => inner.position(),
+    }
+}
+
+impl<S: Positioned<Ok = char>> Positioned for IndentStream<S>
+where
+    S::Locator: Clone,
+{
+    type Locator = S::Locator;
+
+    #[inline]
+    fn position(&self) -> Self::Locator {
+        match self.layout.peeked_pos.front() {
+            Some(pos) => pos.clone(),
+            None => position(&self.inner, &self.layout),
+        }
+    }
+}
+
+/// A marker for [`IndentStream`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub struct IndentMarker<M, L> {
+    inner: M,
+    layout: Layout<L>,
+}
+
+impl<S: Positioned<Ok = char> + Rewind> Rewind for IndentStream<S>
+where
+    S::Locator: Clone,
+{
+    type Marker = IndentMarker<S::Marker, S::Locator>;
+
+    #[inline]
+    fn mark(self: Pin<&mut Self>) -> Result<Self::Marker, Self::Error> {
+        let this = self.project();
+        Ok(IndentMarker {
+            inner: this.inner.mark().map_err(IndentError::Stream)?,
+            layout: this.layout.clone(),
+        })
+    }
+
+    #[inline]
+    fn rewind(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        let this = self.project();
+        this.inner
+            .rewind(marker.inner)
+            .map_err(IndentError::Stream)?;
+        *this.layout = marker.layout;
+        Ok(())
+    }
+
+    #[inline]
+    fn drop_marker(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        self.project()
+            .inner
+            .drop_marker(marker.inner)
+            .map_err(IndentError::Stream)
+    }
+}
+
+impl<S: Positioned<Ok = char>> Peek for IndentStream<S>
+where
+    S::Locator: Clone,
+{
+    fn poll_peek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<Result<&[Self::Ok], Self::Error>> {
+        let mut this = self.project();
+        let layout = this.layout;
+        while layout.peeked.len() < n {
+            let pos = position(&*this.inner, layout);
+            match ready!(poll_token(this.inner.as_mut(), layout, cx)) {
+                Some(Ok(token)) => {
+                    layout.peeked.push_back(token);
+                    layout.peeked_pos.push_back(pos);
+                }
+                Some(Err(e)) => return Poll::Ready(Err(e)),
+                None => break,
+            }
+        }
+        let peeked = layout.peeked.make_contiguous();
+        let len = peeked.len().min(n);
+        Poll::Ready(Ok(&peeked[..len]))
+    }
+}
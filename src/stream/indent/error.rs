@@ -0,0 +1,31 @@
+use core::fmt;
+
+/// An error type for [`IndentStream`].
+///
+/// [`IndentStream`]: crate::stream::indent::IndentStream
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub enum IndentError<S> {
+    Stream(S),
+    Dedent,
+}
+
+impl<S: fmt::Display> fmt::Display for IndentError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stream(e) => write!(f, "{}", e),
+            Self::Dedent => write!(f, "unindent does not match any outer indentation level"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+impl<S: std::error::Error + 'static> std::error::Error for IndentError<S> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Stream(e) => Some(e),
+            Self::Dedent => None,
+        }
+    }
+}
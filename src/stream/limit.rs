@@ -0,0 +1,201 @@
+//! Limits resources consumed by parsers.
+
+mod error;
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::{ready, FusedStream, Stream, TryStream};
+use pin_project_lite::pin_project;
+
+use crate::stream::context::Contextual;
+use crate::stream::peek::Peek;
+use crate::stream::{Positioned, Rewind};
+pub use error::LimitError;
+
+/// A stream that tracks the nesting depth of parsers.
+///
+/// Parsers wrapped by [`nested`] call [`enter`] before parsing and [`exit`] after parsing.
+///
+/// [`nested`]: crate::parser::ParserExt::nested
+/// [`enter`]: Self::enter
+/// [`exit`]: Self::exit
+pub trait Nesting: TryStream {
+    /// Increments the nesting depth, returns an error if it exceeds the limit.
+    fn enter(self: Pin<&mut Self>) -> Result<(), Self::Error>;
+
+    /// Decrements the nesting depth.
+    fn exit(self: Pin<&mut Self>);
+}
+
+pin_project! {
+    /// Wraps [`TryStream`], limits the nesting depth of parsers and the number of tokens consumed.
+    ///
+    /// Exceeding the limits results in [`LimitError`], which aborts parsing immediately like other
+    /// errors from streams. Tokens read again after rewinding are counted too, so the fuel also
+    /// bounds the work of backtracking.
+    ///
+    /// [`TryStream`]: futures_core::stream::TryStream
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Limited<S> {
+        #[pin]
+        inner: S,
+        depth: usize,
+        max_depth: usize,
+        fuel: usize,
+    }
+}
+
+impl<S> From<S> for Limited<S> {
+    #[inline]
+    fn from(inner: S) -> Self {
+        Self {
+            inner,
+            depth: 0,
+            max_depth: usize::MAX,
+            fuel: usize::MAX,
+        }
+    }
+}
+
+impl<S> Limited<S> {
+    /// Creates a new instance, without any limits.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self::from(inner)
+    }
+
+    /// Sets the maximum nesting depth.
+    #[inline]
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum number of tokens consumed.
+    #[inline]
+    pub fn fuel(mut self, fuel: usize) -> Self {
+        self.fuel = fuel;
+        self
+    }
+
+    /// Returns the remaining fuel.
+    #[inline]
+    pub fn remaining_fuel(&self) -> usize {
+        self.fuel
+    }
+
+    /// Extracts the original stream.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: TryStream + FusedStream> FusedStream for Limited<S> {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<S: TryStream> Stream for Limited<S> {
+    type Item = Result<S::Ok, LimitError<S::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let res = ready!(this.inner.try_poll_next(cx));
+        if let Some(Ok(_)) = res {
+            if *this.fuel == 0 {
+                return Poll::Ready(Some(Err(LimitError::Fuel)));
+            }
+            *this.fuel -= 1;
+        }
+        Poll::Ready(res.map(|r| r.map_err(LimitError::Stream)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S: Positioned> Positioned for Limited<S> {
+    type Locator = S::Locator;
+
+    #[inline]
+    fn position(&self) -> Self::Locator {
+        self.inner.position()
+    }
+}
+
+impl<S: Rewind> Rewind for Limited<S> {
+    type Marker = S::Marker;
+
+    #[inline]
+    fn mark(self: Pin<&mut Self>) -> Result<Self::Marker, Self::Error> {
+        self.project().inner.mark().map_err(LimitError::Stream)
+    }
+
+    #[inline]
+    fn rewind(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        self.project()
+            .inner
+            .rewind(marker)
+            .map_err(LimitError::Stream)
+    }
+
+    #[inline]
+    fn drop_marker(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        self.project()
+            .inner
+            .drop_marker(marker)
+            .map_err(LimitError::Stream)
+    }
+}
+
+// Peeking doesn't consume fuel, since the tokens will be counted when they are consumed.
+impl<S: Peek> Peek for Limited<S> {
+    #[inline]
+    fn poll_peek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<Result<&[Self::Ok], Self::Error>> {
+        self.project()
+            .inner
+            .poll_peek(cx, n)
+            .map(|r| r.map_err(LimitError::Stream))
+    }
+}
+
+impl<S: Contextual> Contextual for Limited<S> {
+    type Ctx = S::Ctx;
+
+    #[inline]
+    fn ctx(&self) -> &Self::Ctx {
+        self.inner.ctx()
+    }
+
+    #[inline]
+    fn ctx_mut(self: Pin<&mut Self>) -> &mut Self::Ctx {
+        self.project().inner.ctx_mut()
+    }
+}
+
+impl<S: TryStream> Nesting for Limited<S> {
+    #[inline]
+    fn enter(self: Pin<&mut Self>) -> Result<(), Self::Error> {
+        let this = self.project();
+        if *this.depth >= *this.max_depth {
+            return Err(LimitError::Depth);
+        }
+        *this.depth += 1;
+        Ok(())
+    }
+
+    #[inline]
+    fn exit(self: Pin<&mut Self>) {
+        let this = self.project();
+        *this.depth = this.depth.saturating_sub(1);
+    }
+}
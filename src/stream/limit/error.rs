@@ -0,0 +1,32 @@
+use core::fmt;
+
+/// An error type for [`Limited`].
+///
+/// [`Limited`]: crate::stream::limit::Limited
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LimitError<S> {
+    Stream(S),
+    Depth,
+    Fuel,
+}
+
+impl<S: fmt::Display> fmt::Display for LimitError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stream(e) => write!(f, "{}", e),
+            Self::Depth => write!(f, "exceeded the maximum nesting depth"),
+            Self::Fuel => write!(f, "exceeded the maximum number of tokens"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+impl<S: std::error::Error + 'static> std::error::Error for LimitError<S> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Stream(e) => Some(e),
+            Self::Depth | Self::Fuel => None,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! Looks at upcoming tokens without consuming them.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::TryStream;
+
+/// A stream that can return references to upcoming tokens without consuming them.
+///
+/// Streams which hold tokens in their buffers can implement it cheaply, so lookahead parsers
+/// like [`followed_by`] don't need to mark and rewind the stream.
+///
+/// [`BitStream`] and its [`Bytes`] view don't implement it, since their tokens are not stored as
+/// slices.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use core::pin::Pin;
+/// use futures::future::poll_fn;
+/// use somen::prelude::*;
+/// use somen::stream::peek::Peek;
+///
+/// let mut stream = stream::from_iter("abc".chars()).buffered_rewind();
+/// let peeked = poll_fn(|cx| {
+///     Pin::new(&mut stream).poll_peek(cx, 2).map_ok(|tokens| tokens.to_vec())
+/// })
+/// .await;
+/// assert_eq!(peeked, Ok(vec!['a', 'b']));
+///
+/// // Peeked tokens are still there.
+/// assert_eq!(any().parse(&mut stream).await, Ok('a'));
+/// # });
+/// ```
+///
+/// [`followed_by`]: crate::parser::followed_by
+/// [`BitStream`]: crate::stream::bits::BitStream
+/// [`Bytes`]: crate::stream::bits::Bytes
+pub trait Peek: TryStream {
+    /// Polls the next `n` tokens without consuming them.
+    ///
+    /// The returned slice is shorter than `n` only if the stream has ended.
+    fn poll_peek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<Result<&[Self::Ok], Self::Error>>;
+}
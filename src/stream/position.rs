@@ -3,7 +3,7 @@
 mod locator;
 mod positioned;
 
-pub use locator::{LineCol, Locator};
+pub use locator::{Column, LineCol, Locator, Offset};
 pub use positioned::PositionedStream;
 
 use futures_core::TryStream;
@@ -20,6 +20,43 @@ macro_rules! locator_impl {
     };
 }
 
+/// A trait for positions that indicate a column in a line.
+///
+/// It is required by parsers that depend on indentation.
+pub trait Column {
+    /// Returns the column index.
+    fn column(&self) -> usize;
+}
+
+/// A trait for positions that indicate an offset from the beginning of the input.
+///
+/// It is required to convert errors into diagnostics of other crates. Note that [`miette`]
+/// counts offsets in bytes, while [`ariadne`] counts them in characters by default.
+///
+/// [`miette`]: https://docs.rs/miette
+/// [`ariadne`]: https://docs.rs/ariadne
+pub trait Offset {
+    /// Returns the offset.
+    fn offset(&self) -> usize;
+}
+
+macro_rules! offset_impl {
+    ($t:ty) => {
+        impl Offset for $t {
+            #[inline]
+            fn offset(&self) -> usize {
+                *self as usize
+            }
+        }
+    };
+}
+
+offset_impl! { u8 }
+offset_impl! { u16 }
+offset_impl! { u32 }
+offset_impl! { u64 }
+offset_impl! { usize }
+
 locator_impl! { i8 }
 locator_impl! { i16 }
 locator_impl! { i32 }
@@ -67,6 +104,13 @@ impl PartialOrd for LineCol {
     }
 }
 
+impl Column for LineCol {
+    #[inline]
+    fn column(&self) -> usize {
+        self.col
+    }
+}
+
 impl Locator<char> for LineCol {
     fn next(&mut self, token: &char) {
         if *token == '\n' {
@@ -4,10 +4,39 @@ use futures_core::{ready, FusedStream, Stream, TryStream};
 use pin_project_lite::pin_project;
 
 use super::Locator;
+use crate::stream::contiguous::Contiguous;
+use crate::stream::peek::Peek;
 use crate::stream::{Positioned, Rewind};
 
 pin_project! {
     /// Wraps [`TryStream`], implements [`Positioned`] trait.
+    ///
+    /// Rewinding the stream restores the position as well.
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::pin::Pin;
+    /// use futures::stream::TryStreamExt;
+    /// use somen::prelude::*;
+    /// use somen::stream::position::LineCol;
+    /// use somen::stream::Rewind;
+    ///
+    /// let mut stream = stream::from_iter("a\nb".chars())
+    ///     .buffered_rewind()
+    ///     .positioned::<LineCol>();
+    /// let marker = Pin::new(&mut stream).mark().unwrap();
+    /// assert_eq!(stream.try_next().await, Ok(Some('a')));
+    /// assert_eq!(stream.try_next().await, Ok(Some('\n')));
+    /// assert_eq!(stream.position(), LineCol { line: 2, col: 1 });
+    ///
+    /// Pin::new(&mut stream).rewind(marker).unwrap();
+    /// assert_eq!(stream.position(), LineCol { line: 1, col: 1 });
+    /// assert_eq!(stream.try_next().await, Ok(Some('a')));
+    /// # });
+    /// ```
+    ///
+    /// [`TryStream`]: futures_core::stream::TryStream
     #[derive(Clone, Debug, PartialEq, Eq)]
     pub struct PositionedStream<S, L> {
         #[pin]
@@ -74,21 +103,58 @@ impl<S: TryStream, L: Locator<S::Ok> + PartialEq + Clone> Positioned for Positio
     }
 }
 
-impl<S: Rewind, L: Locator<S::Ok>> Rewind for PositionedStream<S, L> {
-    type Marker = S::Marker;
+impl<S: Rewind, L: Locator<S::Ok> + Clone> Rewind for PositionedStream<S, L> {
+    type Marker = (S::Marker, L);
 
     #[inline]
     fn mark(self: Pin<&mut Self>) -> Result<Self::Marker, Self::Error> {
-        self.project().inner.mark()
+        let this = self.project();
+        Ok((this.inner.mark()?, this.position.clone()))
     }
 
     #[inline]
     fn rewind(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
-        self.project().inner.rewind(marker)
+        let this = self.project();
+        this.inner.rewind(marker.0)?;
+        // The position must be restored as well as the inner stream.
+        *this.position = marker.1;
+        Ok(())
     }
 
     #[inline]
     fn drop_marker(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
-        self.project().inner.drop_marker(marker)
+        self.project().inner.drop_marker(marker.0)
+    }
+}
+
+impl<S: Peek, L: Locator<S::Ok>> Peek for PositionedStream<S, L> {
+    #[inline]
+    fn poll_peek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<Result<&[Self::Ok], Self::Error>> {
+        self.project().inner.poll_peek(cx, n)
+    }
+}
+
+impl<'a, S, L> Contiguous<'a> for PositionedStream<S, L>
+where
+    S: Contiguous<'a>,
+    S::Ok: 'a,
+    L: Locator<S::Ok> + PartialEq + Clone,
+{
+    #[inline]
+    fn remaining(&self) -> &'a [Self::Ok] {
+        self.inner.remaining()
+    }
+
+    fn advance(self: Pin<&mut Self>, n: usize) {
+        let this = self.project();
+        let remaining = this.inner.remaining();
+        for token in &remaining[..n.min(remaining.len())] {
+            this.position.next(token);
+        }
+        this.inner.advance(n);
     }
 }
@@ -4,6 +4,7 @@ use core::task::{Context, Poll};
 use futures_core::{ready, FusedStream, Stream, TryStream};
 use pin_project_lite::pin_project;
 
+use crate::stream::peek::Peek;
 use crate::stream::{Positioned, Rewind};
 
 pin_project! {
@@ -127,3 +128,25 @@ where
         Ok(())
     }
 }
+
+impl<S: TryStream> Peek for VecRecorder<S>
+where
+    S::Ok: Clone,
+{
+    fn poll_peek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<Result<&[Self::Ok], Self::Error>> {
+        let mut this = self.project();
+        while this.record.len() < this.position.saturating_add(n) {
+            match ready!(this.inner.as_mut().try_poll_next(cx)) {
+                Some(Ok(i)) => this.record.push(i),
+                Some(Err(e)) => return Poll::Ready(Err(e)),
+                None => break,
+            }
+        }
+        let rest = &this.record[*this.position..];
+        Poll::Ready(Ok(&rest[..n.min(rest.len())]))
+    }
+}
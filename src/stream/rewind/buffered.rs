@@ -7,6 +7,7 @@ use core::task::{Context, Poll};
 use futures_core::{ready, FusedStream, Stream, TryStream};
 use pin_project_lite::pin_project;
 
+use crate::stream::peek::Peek;
 use crate::stream::{Positioned, Rewind};
 pub use error::BufferedError;
 
@@ -146,3 +147,26 @@ where
         }
     }
 }
+
+impl<S: TryStream> Peek for BufferedRewinder<S>
+where
+    S::Ok: Clone,
+{
+    fn poll_peek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<Result<&[Self::Ok], Self::Error>> {
+        let mut this = self.project();
+        // Peeked tokens are buffered even without markers, they will be popped by `poll_next`.
+        while *this.buffer_offset + this.buffer.len() < this.position.saturating_add(n) {
+            match ready!(this.inner.as_mut().try_poll_next(cx)) {
+                Some(Ok(i)) => this.buffer.push_back(i),
+                Some(Err(e)) => return Poll::Ready(Err(BufferedError::Stream(e))),
+                None => break,
+            }
+        }
+        let rest = &this.buffer.make_contiguous()[*this.position - *this.buffer_offset..];
+        Poll::Ready(Ok(&rest[..n.min(rest.len())]))
+    }
+}
@@ -0,0 +1,351 @@
+//! Traces parsers and streams for debugging.
+
+use core::fmt;
+use core::ops::Range;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::{FusedStream, Stream, TryStream};
+use pin_project_lite::pin_project;
+
+use crate::error::Error;
+use crate::stream::context::Contextual;
+use crate::stream::contiguous::Contiguous;
+use crate::stream::limit::Nesting;
+use crate::stream::peek::Peek;
+use crate::stream::{Positioned, Rewind};
+
+/// Events emitted by parsers wrapped by [`trace`] and by [`TraceStream`].
+///
+/// [`trace`]: crate::parser::ParserExt::trace
+#[derive(Debug, PartialEq, Eq)]
+pub enum TraceEvent<'a, L> {
+    /// Started parsing.
+    Enter { name: &'static str, position: L },
+    /// Succeeded parsing.
+    Success { name: &'static str, span: Range<L> },
+    /// Failed parsing.
+    Failure {
+        name: &'static str,
+        error: &'a Error<L>,
+        exclusive: bool,
+    },
+    /// Marked the position.
+    Mark { position: L },
+    /// Rewound to the position.
+    Rewind { position: L },
+    /// Dropped a marker without rewinding.
+    DropMarker { position: L },
+}
+
+/// A receiver of [`TraceEvent`]s.
+pub trait TraceSink<L> {
+    /// Receives an event.
+    fn event(&mut self, event: TraceEvent<'_, L>);
+}
+
+impl<L, F: FnMut(TraceEvent<'_, L>)> TraceSink<L> for F {
+    #[inline]
+    fn event(&mut self, event: TraceEvent<'_, L>) {
+        self(event)
+    }
+}
+
+/// A stream that receives [`TraceEvent`]s from parsers.
+pub trait Traced: Positioned {
+    /// Emits an event.
+    fn trace(self: Pin<&mut Self>, event: TraceEvent<'_, Self::Locator>);
+}
+
+pin_project! {
+    /// Wraps a stream, implements [`Traced`] trait and traces [`Rewind`] operations, by sending
+    /// events to a [`TraceSink`].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct TraceStream<S, T> {
+        #[pin]
+        inner: S,
+        sink: T,
+    }
+}
+
+impl<S, T> TraceStream<S, T> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: S, sink: T) -> Self {
+        Self { inner, sink }
+    }
+
+    /// Returns a reference to the sink.
+    #[inline]
+    pub fn sink(&self) -> &T {
+        &self.sink
+    }
+
+    /// Extracts the original stream and the sink.
+    #[inline]
+    pub fn into_inner(self) -> (S, T) {
+        (self.inner, self.sink)
+    }
+}
+
+impl<S: TryStream + FusedStream, T> FusedStream for TraceStream<S, T> {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<S: TryStream, T> Stream for TraceStream<S, T> {
+    type Item = Result<S::Ok, S::Error>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.try_poll_next(cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S: Positioned, T> Positioned for TraceStream<S, T> {
+    type Locator = S::Locator;
+
+    #[inline]
+    fn position(&self) -> Self::Locator {
+        self.inner.position()
+    }
+}
+
+impl<S: Rewind + Positioned, T: TraceSink<S::Locator>> Rewind for TraceStream<S, T> {
+    type Marker = S::Marker;
+
+    fn mark(self: Pin<&mut Self>) -> Result<Self::Marker, Self::Error> {
+        let mut this = self.project();
+        let marker = this.inner.as_mut().mark()?;
+        this.sink.event(TraceEvent::Mark {
+            position: this.inner.position(),
+        });
+        Ok(marker)
+    }
+
+    fn rewind(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        let mut this = self.project();
+        this.inner.as_mut().rewind(marker)?;
+        this.sink.event(TraceEvent::Rewind {
+            position: this.inner.position(),
+        });
+        Ok(())
+    }
+
+    fn drop_marker(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        let mut this = self.project();
+        this.inner.as_mut().drop_marker(marker)?;
+        this.sink.event(TraceEvent::DropMarker {
+            position: this.inner.position(),
+        });
+        Ok(())
+    }
+}
+
+impl<'a, S: Contiguous<'a>, T> Contiguous<'a> for TraceStream<S, T>
+where
+    S::Ok: 'a,
+{
+    #[inline]
+    fn remaining(&self) -> &'a [Self::Ok] {
+        self.inner.remaining()
+    }
+
+    #[inline]
+    fn advance(self: Pin<&mut Self>, n: usize) {
+        self.project().inner.advance(n)
+    }
+}
+
+impl<S: Peek, T> Peek for TraceStream<S, T> {
+    #[inline]
+    fn poll_peek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<Result<&[Self::Ok], Self::Error>> {
+        self.project().inner.poll_peek(cx, n)
+    }
+}
+
+impl<S: Positioned, T: TraceSink<S::Locator>> Traced for TraceStream<S, T> {
+    #[inline]
+    fn trace(self: Pin<&mut Self>, event: TraceEvent<'_, Self::Locator>) {
+        self.project().sink.event(event)
+    }
+}
+
+impl<S, T> Contextual for TraceStream<S, T>
+where
+    S: Contextual,
+{
+    type Ctx = S::Ctx;
+
+    #[inline]
+    fn ctx(&self) -> &Self::Ctx {
+        self.inner.ctx()
+    }
+
+    #[inline]
+    fn ctx_mut(self: Pin<&mut Self>) -> &mut Self::Ctx {
+        self.project().inner.ctx_mut()
+    }
+}
+
+impl<S: Nesting, T> Nesting for TraceStream<S, T> {
+    #[inline]
+    fn enter(self: Pin<&mut Self>) -> Result<(), Self::Error> {
+        self.project().inner.enter()
+    }
+
+    #[inline]
+    fn exit(self: Pin<&mut Self>) {
+        self.project().inner.exit()
+    }
+}
+
+/// A [`TraceSink`] prints events as an indented text, like a call tree.
+///
+/// # Examples
+/// ```
+/// # futures::executor::block_on(async {
+/// use somen::prelude::*;
+/// use somen::stream::trace::PrettyPrinter;
+///
+/// let mut parser = tag("ab")
+///     .trace("ab")
+///     .or(tag("ac").trace("ac"))
+///     .trace("choice");
+///
+/// let mut stream = stream::from_iter("ac".chars())
+///     .buffered_rewind()
+///     .traced(PrettyPrinter::new(String::new()));
+/// assert_eq!(parser.parse(&mut stream).await, Ok("ac"));
+///
+/// let (_, printer) = stream.into_inner();
+/// assert_eq!(
+///     printer.into_inner(),
+///     "choice @ 0
+///   mark @ 0
+///   ab @ 0
+///   ab failed at 0..1, expected ab
+///   rewind to 0
+///   ac @ 0
+///   ac succeeded at 0..2
+/// choice succeeded at 0..2
+/// ",
+/// );
+/// # });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PrettyPrinter<W> {
+    writer: W,
+    depth: usize,
+}
+
+impl<W> PrettyPrinter<W> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(writer: W) -> Self {
+        Self { writer, depth: 0 }
+    }
+
+    /// Extracts the writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: fmt::Write, L: fmt::Debug> TraceSink<L> for PrettyPrinter<W> {
+    fn event(&mut self, event: TraceEvent<'_, L>) {
+        if matches!(
+            event,
+            TraceEvent::Success { .. } | TraceEvent::Failure { .. }
+        ) {
+            self.depth = self.depth.saturating_sub(1);
+        }
+        let indent = self.depth * 2;
+        // Errors on writing are ignored, so that parsing won't be affected.
+        let _ = match event {
+            TraceEvent::Enter { name, position } => {
+                self.depth += 1;
+                writeln!(self.writer, "{:indent$}{} @ {:?}", "", name, position)
+            }
+            TraceEvent::Success { name, span } => writeln!(
+                self.writer,
+                "{:indent$}{} succeeded at {:?}..{:?}",
+                "", name, span.start, span.end
+            ),
+            TraceEvent::Failure {
+                name,
+                error,
+                exclusive,
+            } => writeln!(
+                self.writer,
+                "{:indent$}{} failed at {:?}..{:?}, expected {}{}",
+                "",
+                name,
+                error.position.start,
+                error.position.end,
+                error.expects,
+                if exclusive { " (exclusive)" } else { "" }
+            ),
+            TraceEvent::Mark { position } => {
+                writeln!(self.writer, "{:indent$}mark @ {:?}", "", position)
+            }
+            TraceEvent::Rewind { position } => {
+                writeln!(self.writer, "{:indent$}rewind to {:?}", "", position)
+            }
+            TraceEvent::DropMarker { position } => {
+                writeln!(self.writer, "{:indent$}drop marker @ {:?}", "", position)
+            }
+        };
+    }
+}
+
+/// A [`TraceSink`] emits events to the `tracing` crate, at the `TRACE` level with the target
+/// `somen`.
+#[cfg(feature = "tracing")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "tracing")))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TracingSink;
+
+#[cfg(feature = "tracing")]
+impl<L: fmt::Debug> TraceSink<L> for TracingSink {
+    fn event(&mut self, event: TraceEvent<'_, L>) {
+        match event {
+            TraceEvent::Enter { name, position } => {
+                tracing::trace!(target: "somen", name, ?position, "enter")
+            }
+            TraceEvent::Success { name, span } => {
+                tracing::trace!(target: "somen", name, ?span, "success")
+            }
+            TraceEvent::Failure {
+                name,
+                error,
+                exclusive,
+            } => tracing::trace!(
+                target: "somen",
+                name,
+                span = ?error.position,
+                expects = %error.expects,
+                exclusive,
+                "failure"
+            ),
+            TraceEvent::Mark { position } => tracing::trace!(target: "somen", ?position, "mark"),
+            TraceEvent::Rewind { position } => {
+                tracing::trace!(target: "somen", ?position, "rewind")
+            }
+            TraceEvent::DropMarker { position } => {
+                tracing::trace!(target: "somen", ?position, "drop marker")
+            }
+        }
+    }
+}
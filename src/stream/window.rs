@@ -0,0 +1,197 @@
+//! Limits streams to windows of tokens.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::{ready, FusedStream, Stream, TryStream};
+use pin_project_lite::pin_project;
+
+use crate::stream::context::Contextual;
+use crate::stream::limit::Nesting;
+use crate::stream::peek::Peek;
+use crate::stream::{Positioned, Rewind};
+
+/// A stream that can be limited to a window of the following tokens.
+///
+/// It is used by framing parsers like [`length_prefixed`].
+///
+/// [`length_prefixed`]: crate::parser::length_prefixed
+pub trait Window: TryStream {
+    /// Limits the stream to the next `len` tokens (within the current window), and returns the
+    /// end of the current window.
+    fn open_window(self: Pin<&mut Self>, len: usize) -> Option<usize>;
+
+    /// Restores the end of window returned by [`open_window`].
+    ///
+    /// [`open_window`]: Self::open_window
+    fn close_window(self: Pin<&mut Self>, end: Option<usize>);
+
+    /// Returns the number of remaining tokens in the current window, or [`None`] if the stream is
+    /// not limited.
+    fn remaining(&self) -> Option<usize>;
+}
+
+pin_project! {
+    /// Wraps [`TryStream`], implements [`Window`] trait.
+    ///
+    /// [`TryStream`]: futures_core::stream::TryStream
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct WindowStream<S> {
+        #[pin]
+        inner: S,
+        consumed: usize,
+        end: Option<usize>,
+    }
+}
+
+impl<S> From<S> for WindowStream<S> {
+    #[inline]
+    fn from(inner: S) -> Self {
+        Self {
+            inner,
+            consumed: 0,
+            end: None,
+        }
+    }
+}
+
+impl<S> WindowStream<S> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self::from(inner)
+    }
+
+    /// Extracts the original stream.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: TryStream + FusedStream> FusedStream for WindowStream<S> {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.remaining() == Some(0) || self.inner.is_terminated()
+    }
+}
+
+impl<S: TryStream> Stream for WindowStream<S> {
+    type Item = Result<S::Ok, S::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if matches!(*this.end, Some(end) if *this.consumed >= end) {
+            return Poll::Ready(None);
+        }
+        let res = ready!(this.inner.try_poll_next(cx));
+        if let Some(Ok(_)) = res {
+            *this.consumed += 1;
+        }
+        Poll::Ready(res)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        match self.remaining() {
+            Some(rem) => (lower.min(rem), Some(upper.map_or(rem, |u| u.min(rem)))),
+            None => (lower, upper),
+        }
+    }
+}
+
+impl<S: Positioned> Positioned for WindowStream<S> {
+    type Locator = S::Locator;
+
+    #[inline]
+    fn position(&self) -> Self::Locator {
+        self.inner.position()
+    }
+}
+
+impl<S: Rewind> Rewind for WindowStream<S> {
+    type Marker = (S::Marker, usize);
+
+    #[inline]
+    fn mark(self: Pin<&mut Self>) -> Result<Self::Marker, Self::Error> {
+        let this = self.project();
+        Ok((this.inner.mark()?, *this.consumed))
+    }
+
+    #[inline]
+    fn rewind(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        let this = self.project();
+        this.inner.rewind(marker.0)?;
+        *this.consumed = marker.1;
+        Ok(())
+    }
+
+    #[inline]
+    fn drop_marker(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        self.project().inner.drop_marker(marker.0)
+    }
+}
+
+impl<S: Peek> Peek for WindowStream<S> {
+    #[inline]
+    fn poll_peek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<Result<&[Self::Ok], Self::Error>> {
+        // Tokens after the end of the window can't be seen.
+        let n = self.remaining().map_or(n, |rem| n.min(rem));
+        self.project()
+            .inner
+            .poll_peek(cx, n)
+            .map_ok(|tokens| &tokens[..tokens.len().min(n)])
+    }
+}
+
+impl<S: TryStream> Window for WindowStream<S> {
+    #[inline]
+    fn open_window(self: Pin<&mut Self>, len: usize) -> Option<usize> {
+        let this = self.project();
+        let end = this.consumed.saturating_add(len);
+        this.end.replace(match *this.end {
+            Some(outer) => end.min(outer),
+            None => end,
+        })
+    }
+
+    #[inline]
+    fn close_window(self: Pin<&mut Self>, end: Option<usize>) {
+        *self.project().end = end;
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        self.end.map(|end| end.saturating_sub(self.consumed))
+    }
+}
+
+impl<S: Contextual> Contextual for WindowStream<S> {
+    type Ctx = S::Ctx;
+
+    #[inline]
+    fn ctx(&self) -> &Self::Ctx {
+        self.inner.ctx()
+    }
+
+    #[inline]
+    fn ctx_mut(self: Pin<&mut Self>) -> &mut Self::Ctx {
+        self.project().inner.ctx_mut()
+    }
+}
+
+impl<S: Nesting> Nesting for WindowStream<S> {
+    #[inline]
+    fn enter(self: Pin<&mut Self>) -> Result<(), Self::Error> {
+        self.project().inner.enter()
+    }
+
+    #[inline]
+    fn exit(self: Pin<&mut Self>) {
+        self.project().inner.exit()
+    }
+}
@@ -0,0 +1,336 @@
+//! Utilities for testing parsers resumed from [`Poll::Pending`].
+//!
+//! Parsers have to save their progress to [`State`] when the input returns [`Poll::Pending`], and
+//! resume from there on the next call. Inputs like [`SliceStream`] never return it, so
+//! [`Interrupted`] injects [`Poll::Pending`] at the points given by a [`Schedule`], and
+//! [`check_resumable`] compares results of interrupted runs with an uninterrupted one.
+//!
+//! # Examples
+//! ```
+//! use somen::prelude::*;
+//! use somen::testing::check_resumable;
+//!
+//! let res = check_resumable(b"abcd", || tokens(b"abc").skip(token(b'd')));
+//! assert_eq!(res, Ok(b"abc"));
+//! ```
+//!
+//! [`State`]: crate::parser::Parser::State
+//! [`SliceStream`]: crate::stream::SliceStream
+
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use core::fmt;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+use futures_core::{ready, FusedStream, Stream, TryStream};
+use pin_project_lite::pin_project;
+
+use crate::error::{ParseError, ParseResult, Status};
+use crate::parser::Parser;
+use crate::stream::contiguous::Contiguous;
+use crate::stream::peek::Peek;
+use crate::stream::{Positioned, Rewind, SliceStream};
+
+/// Points where [`Interrupted`] returns [`Poll::Pending`].
+///
+/// Each interruption is followed by a successful poll, so parsers always make progress.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Schedule {
+    /// Never interrupts.
+    Never,
+    /// Interrupts before every poll.
+    Always,
+    /// Interrupts before every `n`-th poll.
+    Every(usize),
+    /// Interrupts before a half of polls at random, the value is the seed.
+    Random(u64),
+}
+
+/// Schedules used by [`check_resumable`].
+const SCHEDULES: [Schedule; 12] = [
+    Schedule::Always,
+    Schedule::Every(2),
+    Schedule::Every(3),
+    Schedule::Every(5),
+    Schedule::Random(0),
+    Schedule::Random(1),
+    Schedule::Random(2),
+    Schedule::Random(3),
+    Schedule::Random(4),
+    Schedule::Random(5),
+    Schedule::Random(6),
+    Schedule::Random(7),
+];
+
+/// The maximum number of [`Poll::Pending`] in a run, to detect parsers making no progress.
+const MAX_PENDING: usize = 1 << 20;
+
+pin_project! {
+    /// Wraps a stream, returns [`Poll::Pending`] and wakes the task at the points given by a
+    /// [`Schedule`].
+    ///
+    /// Both [`poll_next`] and [`poll_peek`] are interrupted, while [`Contiguous`] is forwarded
+    /// as is. Tokens already returned by [`poll_peek`] are treated as buffered, so consuming them
+    /// is never interrupted.
+    ///
+    /// [`poll_next`]: Stream::poll_next
+    /// [`poll_peek`]: Peek::poll_peek
+    #[derive(Clone, Debug)]
+    pub struct Interrupted<S> {
+        #[pin]
+        inner: S,
+        schedule: Schedule,
+        polls: usize,
+        rng: u64,
+        pending: bool,
+        buffered: usize,
+    }
+}
+
+impl<S> Interrupted<S> {
+    /// Creates a new instance.
+    #[inline]
+    pub fn new(inner: S, schedule: Schedule) -> Self {
+        let rng = match schedule {
+            // Mixes the seed by SplitMix64, since xorshift can't start from zero.
+            Schedule::Random(seed) => {
+                let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+                z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+                (z ^ (z >> 31)) | 1
+            }
+            _ => 0,
+        };
+        Self {
+            inner,
+            schedule,
+            polls: 0,
+            rng,
+            pending: false,
+            buffered: 0,
+        }
+    }
+
+    /// Extracts the original stream.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// Returns `true` and wakes the task if the current poll should be interrupted.
+    fn interrupt(self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool {
+        let this = self.project();
+        if *this.pending {
+            *this.pending = false;
+            return false;
+        }
+        *this.polls += 1;
+        *this.pending = match *this.schedule {
+            Schedule::Never => false,
+            Schedule::Always => true,
+            Schedule::Every(n) => this.polls.is_multiple_of(n),
+            Schedule::Random(_) => {
+                *this.rng ^= *this.rng << 13;
+                *this.rng ^= *this.rng >> 7;
+                *this.rng ^= *this.rng << 17;
+                *this.rng & 1 == 1
+            }
+        };
+        if *this.pending {
+            cx.waker().wake_by_ref();
+        }
+        *this.pending
+    }
+}
+
+impl<S: TryStream + FusedStream> FusedStream for Interrupted<S> {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<S: TryStream> Stream for Interrupted<S> {
+    type Item = Result<S::Ok, S::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.buffered > 0 {
+            *self.as_mut().project().buffered -= 1;
+        } else if self.as_mut().interrupt(cx) {
+            return Poll::Pending;
+        }
+        self.project().inner.try_poll_next(cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S: Positioned> Positioned for Interrupted<S> {
+    type Locator = S::Locator;
+
+    #[inline]
+    fn position(&self) -> Self::Locator {
+        self.inner.position()
+    }
+}
+
+impl<S: Rewind> Rewind for Interrupted<S> {
+    type Marker = S::Marker;
+
+    #[inline]
+    fn mark(self: Pin<&mut Self>) -> Result<Self::Marker, Self::Error> {
+        self.project().inner.mark()
+    }
+
+    #[inline]
+    fn rewind(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        let this = self.project();
+        *this.buffered = 0;
+        this.inner.rewind(marker)
+    }
+
+    #[inline]
+    fn drop_marker(self: Pin<&mut Self>, marker: Self::Marker) -> Result<(), Self::Error> {
+        self.project().inner.drop_marker(marker)
+    }
+}
+
+impl<S: Peek> Peek for Interrupted<S> {
+    fn poll_peek(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<Result<&[Self::Ok], Self::Error>> {
+        if n > self.buffered && self.as_mut().interrupt(cx) {
+            return Poll::Pending;
+        }
+        let this = self.project();
+        let res = ready!(this.inner.poll_peek(cx, n));
+        if let Ok(tokens) = &res {
+            *this.buffered = tokens.len();
+        }
+        Poll::Ready(res)
+    }
+}
+
+impl<'a, S: Contiguous<'a>> Contiguous<'a> for Interrupted<S>
+where
+    S::Ok: 'a,
+{
+    #[inline]
+    fn remaining(&self) -> &'a [Self::Ok] {
+        self.inner.remaining()
+    }
+
+    #[inline]
+    fn advance(self: Pin<&mut Self>, n: usize) {
+        self.project().inner.advance(n)
+    }
+}
+
+/// Parses `input` by parsers from `parser` with and without interruptions, panics if the results
+/// differ, and returns the result without interruptions.
+///
+/// See [`check_resumable_with`] for details.
+pub fn check_resumable<'a, T, F, P>(
+    input: &'a [T],
+    parser: F,
+) -> ParseResult<P::Output, Interrupted<SliceStream<'a, T>>>
+where
+    T: Clone,
+    F: FnMut() -> P,
+    P: Parser<Interrupted<SliceStream<'a, T>>>,
+    P::Output: PartialEq + fmt::Debug,
+{
+    check_resumable_with(
+        |schedule| Interrupted::new(SliceStream::from(input), schedule),
+        parser,
+    )
+}
+
+/// Parses inputs from `input` by parsers from `parser` with and without interruptions, panics
+/// if the results differ, and returns the result without interruptions.
+///
+/// `input` receives a [`Schedule`] to wrap the stream by [`Interrupted`], so other wrappers can
+/// be added on it. Each run compares the whole [`Status`] including errors of the lookahead, and
+/// the position of the input after parsing. It also panics if the parser returns
+/// [`Poll::Pending`] without waking the task.
+///
+/// # Examples
+/// ```
+/// use somen::prelude::*;
+/// use somen::testing::{check_resumable_with, Interrupted};
+///
+/// let res = check_resumable_with(
+///     |schedule| Interrupted::new(stream::from_slice(b"[[]]"), schedule).limited(),
+///     || token(b'[').repeat(..).count().nested(),
+/// );
+/// assert_eq!(res, Ok(2));
+/// ```
+pub fn check_resumable_with<I, S, F, P>(mut input: S, mut parser: F) -> ParseResult<P::Output, I>
+where
+    S: FnMut(Schedule) -> I,
+    I: Positioned + Unpin,
+    I::Locator: PartialEq + fmt::Debug,
+    I::Error: PartialEq + fmt::Debug,
+    F: FnMut() -> P,
+    P: Parser<I>,
+    P::Output: PartialEq + fmt::Debug,
+{
+    let expected = run(&mut parser(), &mut input(Schedule::Never));
+    for schedule in SCHEDULES {
+        let actual = run(&mut parser(), &mut input(schedule));
+        assert_eq!(actual, expected, "results differ with {:?}", schedule);
+    }
+
+    match expected.0 {
+        Ok(Status::Success(val, _)) => Ok(val),
+        Ok(Status::Failure(err, _)) => Err(ParseError::Parser(err)),
+        Err(err) => Err(ParseError::Stream(err)),
+    }
+}
+
+type RunResult<O, I> = (
+    Result<Status<O, <I as Positioned>::Locator>, <I as TryStream>::Error>,
+    <I as Positioned>::Locator,
+);
+
+/// Polls the parser until it completes, returns the result and the position of the input.
+fn run<P: Parser<I>, I: Positioned + Unpin>(
+    parser: &mut P,
+    input: &mut I,
+) -> RunResult<P::Output, I> {
+    let flag = Arc::new(WakeFlag(AtomicBool::new(false)));
+    let waker = Waker::from(Arc::clone(&flag));
+    let mut cx = Context::from_waker(&waker);
+    let mut state = Default::default();
+    for _ in 0..MAX_PENDING {
+        match parser.poll_parse(Pin::new(&mut *input), &mut cx, &mut state) {
+            Poll::Ready(res) => return (res, input.position()),
+            Poll::Pending => assert!(
+                flag.0.swap(false, Ordering::Relaxed),
+                "the parser returned `Poll::Pending` without waking the task",
+            ),
+        }
+    }
+    panic!("the parser makes no progress");
+}
+
+/// A waker recording whether it was woken.
+struct WakeFlag(AtomicBool);
+
+impl Wake for WakeFlag {
+    #[inline]
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    #[inline]
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,214 @@
+use somen::prelude::*;
+use somen::testing::{check_resumable, check_resumable_with, Interrupted};
+
+use super::chars;
+
+#[test]
+fn any_eof() {
+    let input = chars("ab");
+    assert_eq!(
+        check_resumable(&input, || (any(), any(), eof())),
+        Ok(('a', 'b', ()))
+    );
+    assert!(check_resumable(&input, || (any(), eof())).is_err());
+    assert!(check_resumable(&[] as &[char], any).is_err());
+}
+
+#[test]
+fn value_position() {
+    let input = chars("ab");
+    assert_eq!(
+        check_resumable(&input, || (position(), any(), value(1), position())),
+        Ok((0, 'a', 1, 1)),
+    );
+    assert_eq!(check_resumable(&input, || value_fn(|| 1)), Ok(1));
+}
+
+#[test]
+fn token_not() {
+    let input = chars("ab");
+    assert_eq!(
+        check_resumable(&input, || (token('a'), not('a'))),
+        Ok(('a', 'b'))
+    );
+    assert!(check_resumable(&input, || (token('a'), token('a'))).is_err());
+    assert!(check_resumable(&input, || not('a')).is_err());
+}
+
+#[test]
+fn sets() {
+    let input = chars("a1");
+    assert_eq!(
+        check_resumable(&input, || (one_of('a'..='z'), none_of("abc"))),
+        Ok(('a', '1')),
+    );
+    assert!(check_resumable(&input, || one_of("xyz")).is_err());
+    assert!(check_resumable(&input, || none_of(['a'])).is_err());
+}
+
+#[test]
+fn conditions() {
+    let input = chars("a1");
+    assert_eq!(
+        check_resumable(&input, || (
+            is(|c: &char| c.is_alphabetic()),
+            is_some(|c: char| c.to_digit(10)),
+        )),
+        Ok(('a', 1)),
+    );
+    assert!(check_resumable(&input, || is_not(|c: &char| c.is_alphabetic())).is_err());
+    assert!(check_resumable(&input, || (
+        any(),
+        is_some(|c: char| c.to_digit(8).filter(|d| *d > 1))
+    ))
+    .is_err());
+}
+
+#[test]
+fn lookahead() {
+    use somen::parser::{followed_by, lookahead, not_followed_by};
+
+    let input = chars("abc");
+    assert_eq!(
+        check_resumable(&input, || (
+            followed_by(|c: &char| *c == 'a'),
+            not_followed_by(|c: &char| *c == 'b'),
+            lookahead(2),
+            any(),
+        )),
+        Ok(((), (), vec!['a', 'b'], 'a')),
+    );
+    assert!(check_resumable(&input, || followed_by(|c: &char| *c == 'b')).is_err());
+    assert!(check_resumable(&input, || not_followed_by(|c: &char| *c == 'a')).is_err());
+    assert_eq!(
+        check_resumable(&input, || lookahead(4)),
+        Ok(vec!['a', 'b', 'c'])
+    );
+}
+
+#[test]
+fn lookahead_streams() {
+    use somen::parser::{followed_by, length_prefixed, lookahead, take_while};
+    use somen::stream::indent::IndentToken::{Char, Indent};
+
+    // Tokens after the window can't be seen.
+    let input = |schedule| Interrupted::new(stream::from_slice(b"\x02abc"), schedule).windowed();
+    assert_eq!(
+        check_resumable_with(input, || (
+            length_prefixed(
+                any().map(usize::from),
+                lookahead(3).skip(any().repeat(..).discard())
+            ),
+            followed_by(|b: &u8| *b == b'c'),
+        )),
+        Ok((b"ab".to_vec(), ())),
+    );
+
+    let input = chars("ab");
+    assert_eq!(
+        check_resumable_with(
+            |schedule| Interrupted::new(stream::from_slice(&input), schedule).cst::<()>(),
+            || (
+                lookahead(2),
+                take_while::<String, _, _>(|c: &char| c.is_alphabetic())
+            ),
+        ),
+        Ok((vec!['a', 'b'], String::from("ab"))),
+    );
+
+    let input = chars("a\n b");
+    assert_eq!(
+        check_resumable_with(
+            |schedule| {
+                Interrupted::new(stream::from_slice(&input), schedule)
+                    .positioned::<usize>()
+                    .indent_tokens()
+            },
+            || (any(), any(), lookahead(3), any(), any(), position(), any()),
+        ),
+        Ok((
+            Char('a'),
+            Char('\n'),
+            vec![Char(' '), Indent, Char('b')],
+            Char(' '),
+            Indent,
+            3,
+            Char('b'),
+        )),
+    );
+}
+
+#[test]
+fn take() {
+    use somen::parser::{take, take_until, take_while, take_while1};
+
+    let input = chars("aab--c");
+    assert_eq!(
+        check_resumable(&input, || (
+            take_while::<String, _, _>(|c: &char| *c == 'a'),
+            take_until::<String, _, _>(['-', 'c']),
+            take::<String, _>(2),
+            eof(),
+        )),
+        Ok((
+            String::from("aa"),
+            String::from("b-"),
+            String::from("-c"),
+            ()
+        )),
+    );
+    assert!(check_resumable(&input, || take_while1::<String, _, _>(|c: &char| *c == 'b')).is_err());
+    assert!(check_resumable(&input, || take_until::<String, _, _>(['c', '-'])).is_err());
+    assert!(check_resumable(&input, || take::<String, _>(7)).is_err());
+}
+
+#[test]
+fn tokens_tag() {
+    let input = chars("abcd");
+    assert_eq!(
+        check_resumable(&input, || (tokens(&['a', 'b']), tag("cd"))),
+        Ok((&['a', 'b'], "cd")),
+    );
+    assert!(check_resumable(&input, || tokens(&['a', 'c'])).is_err());
+    assert!(check_resumable(&input, || tag("abd")).is_err());
+    assert!(check_resumable(&input, || tag("abcde")).is_err());
+}
+
+#[test]
+fn regex() {
+    use somen::parser::regex;
+
+    let input = chars("ab12;");
+    assert_eq!(
+        check_resumable(&input, || (regex("[a-z]+"), regex("[0-9]*;?"))),
+        Ok((String::from("ab"), String::from("12;"))),
+    );
+    assert!(check_resumable(&input, || regex("a[0-9]")).is_err());
+}
+
+#[test]
+fn function() {
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+    use futures::TryStream;
+    use somen::error::Status;
+    use somen::parser::function;
+    use somen::stream::SliceStream;
+    use somen::testing::Interrupted;
+
+    type Input<'a> = Interrupted<SliceStream<'a, char>>;
+
+    // Counts tokens until the end, resuming by the count in the state.
+    let input = chars("abc");
+    assert_eq!(
+        check_resumable(&input, || function::<_, _, _, (), _>(
+            |mut input: Pin<&mut Input<'_>>, cx: &mut Context<'_>, count: &mut usize| loop {
+                match futures::ready!(input.as_mut().try_poll_next(cx)?) {
+                    Some(_) => *count += 1,
+                    None => return Poll::Ready(Ok(Status::Success(*count, None))),
+                }
+            }
+        )),
+        Ok(3),
+    );
+}
@@ -0,0 +1,61 @@
+use somen::parser::binary::*;
+use somen::prelude::*;
+use somen::stream::bits::BitPosition;
+use somen::testing::{check_resumable, check_resumable_with, Interrupted};
+
+#[test]
+fn numbers() {
+    let input = b"\x12\x34\x56\x78\x00\x00\xc0\x3f\xff";
+    assert_eq!(
+        check_resumable(input, || (u16_be(), u16_le(), f32_le(), any())),
+        Ok((0x1234, 0x7856, 1.5, 0xff)),
+    );
+    assert!(check_resumable(input, || (u64_be(), i16_le())).is_err());
+}
+
+#[test]
+fn varints() {
+    let input = b"\xe5\x8e\x26\xc0\xbb\x78\x03\x7b\xbd";
+    assert_eq!(
+        check_resumable(input, || (
+            uleb128(),
+            sleb128(),
+            zigzag_varint(),
+            quic_varint()
+        )),
+        Ok((624485, -123456, -2, 15293)),
+    );
+    assert!(check_resumable(b"\x81\x00", uleb128).is_err());
+    assert!(check_resumable(&[0xff; 11], uleb128).is_err());
+    assert!(check_resumable(b"\xbb", quic_varint).is_err());
+}
+
+#[test]
+fn bits_flags() {
+    let input = b"\x85\x83\x00\x01\xff";
+    let res = check_resumable_with(
+        |schedule| Interrupted::new(stream::from_slice(input), schedule).bits(),
+        || {
+            (
+                flag(),
+                bits(4),
+                flag(),
+                bits(6),
+                bits(4),
+                aligned(u16_be()),
+                bits(12),
+            )
+        },
+    );
+    assert!(matches!(
+        res,
+        Err(somen::error::ParseError::Parser(e))
+            if e.position == (BitPosition { byte: 4, bit: 0 }..BitPosition { byte: 5, bit: 0 })
+    ));
+
+    let res = check_resumable_with(
+        |schedule| Interrupted::new(stream::from_slice(input), schedule).bits(),
+        || (flag(), bits(4), flag(), bits(6), bits(4), aligned(u16_be())),
+    );
+    assert_eq!(res, Ok((true, 0, true, 24, 3, 1)));
+}
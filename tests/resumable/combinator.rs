@@ -0,0 +1,123 @@
+use somen::parser::{choice, count_prefixed, dispatch, length_prefixed, permutation, take, window};
+use somen::prelude::*;
+use somen::testing::{check_resumable, check_resumable_with, Interrupted};
+
+use super::chars;
+
+#[test]
+fn or_choice() {
+    let input = chars("abc");
+    assert_eq!(
+        check_resumable(&input, || tag("ax").or(tag("ab")).and(any())),
+        Ok(("ab", 'c')),
+    );
+    assert_eq!(
+        check_resumable(&input, || choice((tag("x"), tag("abd"), tag("abc")))),
+        Ok("abc"),
+    );
+    assert!(check_resumable(&input, || choice((tag("x"), tag("y")))).is_err());
+    // The failure of the first alternative consumed input, so the second one is not tried.
+    assert!(check_resumable(&input, || tag("ab").cut().skip(token('x')).or(tag("abc"))).is_err());
+}
+
+#[test]
+fn dispatch_permutation() {
+    let input = chars("if-b-a");
+    assert_eq!(
+        check_resumable(&input, || dispatch((
+            tag("in").map(|_| 0),
+            tag("if").map(|_| 1),
+            is(|c: &char| c.is_ascii_digit()).map(|_| 2),
+        ))),
+        Ok(1),
+    );
+    assert!(check_resumable(&input, || dispatch((tag("let"), tag("in")))).is_err());
+    assert_eq!(
+        check_resumable(&input, || tag("if").prefix(permutation((
+            tag("-a"),
+            tag("-b").opt(),
+            tag("-c").map(|_| 1).or(value(0)),
+        )))),
+        Ok(("-a", Some("-b"), 0)),
+    );
+}
+
+#[test]
+fn opt_peek_fail() {
+    let input = chars("abc");
+    assert_eq!(
+        check_resumable(&input, || (tag("ax").opt(), tag("ab").peek(), tag("abc"))),
+        Ok((None, "ab", "abc")),
+    );
+    assert_eq!(
+        check_resumable(&input, || tag("x").fail().and(any())),
+        Ok(((), 'a'))
+    );
+    assert!(check_resumable(&input, || tag("ab").fail()).is_err());
+}
+
+#[test]
+fn sequences() {
+    let input = chars("(ab)c");
+    assert_eq!(
+        check_resumable(&input, || tag("ab")
+            .between(token('('), token(')'))
+            .and(any())),
+        Ok(("ab", 'c')),
+    );
+    assert_eq!(
+        check_resumable(&input, || (any(), any(), any(), any(), any()).complete()),
+        Ok(('(', 'a', 'b', ')', 'c')),
+    );
+    assert!(check_resumable(&input, || (any(), any()).complete()).is_err());
+}
+
+#[test]
+fn then() {
+    let input = chars("2abc");
+    assert_eq!(
+        check_resumable(&input, || {
+            is_some(|c: char| c.to_digit(10)).then(|n| take::<String, _>(n as usize))
+        }),
+        Ok(String::from("ab")),
+    );
+    assert_eq!(
+        check_resumable(&input, || {
+            is_some(|c: char| c.to_digit(10))
+                .then(|n| any().times(n as usize))
+                .collect::<String>()
+        }),
+        Ok(String::from("ab")),
+    );
+    assert!(check_resumable(&input, || {
+        is_some(|c: char| c.to_digit(10)).try_then(|n| {
+            if n > 2 {
+                Ok(any())
+            } else {
+                Err("a large number")
+            }
+        })
+    })
+    .is_err());
+}
+
+#[test]
+fn framing() {
+    let input =
+        |schedule| Interrupted::new(stream::from_slice(b"\x02ab\x03xyz"), schedule).windowed();
+    assert_eq!(
+        check_resumable_with(input, || (
+            length_prefixed(any().map(usize::from), any().repeat(..).collect::<Vec<_>>()),
+            count_prefixed(any(), any()).collect::<Vec<_>>(),
+        )),
+        Ok((b"ab".to_vec(), b"xyz".to_vec())),
+    );
+    assert_eq!(
+        check_resumable_with(input, || window(2, any().repeat(..).count())),
+        Ok(2),
+    );
+    // The inner parser must consume the whole frame.
+    assert!(
+        check_resumable_with(input, || length_prefixed(any().map(usize::from), any())).is_err()
+    );
+}
@@ -0,0 +1,248 @@
+use somen::parser::iterable::choice_iterable;
+use somen::prelude::*;
+use somen::testing::check_resumable;
+
+use super::chars;
+
+fn digit<'a>(
+) -> impl Parser<somen::testing::Interrupted<stream::SliceStream<'a, char>>, Output = u32> {
+    is_some(|c: char| c.to_digit(10))
+}
+
+#[test]
+fn repeat_times() {
+    let input = chars("123ab");
+    assert_eq!(
+        check_resumable(&input, || digit().repeat(..).collect::<Vec<_>>()),
+        Ok(vec![1, 2, 3]),
+    );
+    assert_eq!(
+        check_resumable(&input, || digit().repeat(..=2).count()),
+        Ok(2)
+    );
+    assert_eq!(
+        check_resumable(&input, || digit().times(2).collect::<Vec<_>>()),
+        Ok(vec![1, 2]),
+    );
+    assert!(check_resumable(&input, || digit().repeat(4..).count()).is_err());
+    assert!(check_resumable(&input, || digit().times(4).count()).is_err());
+    assert_eq!(
+        check_resumable(&input, || digit().until(token('a')).collect::<Vec<_>>()),
+        Ok(vec![1, 2, 3]),
+    );
+}
+
+#[test]
+fn separated() {
+    let input = chars("1,2,3x");
+    assert_eq!(
+        check_resumable(&input, || digit()
+            .sep_by(token(','), ..)
+            .collect::<Vec<_>>()),
+        Ok(vec![1, 2, 3]),
+    );
+    assert_eq!(
+        check_resumable(&input, || digit()
+            .sep_by_end(token(','), ..)
+            .collect::<Vec<_>>()),
+        Ok(vec![1, 2, 3]),
+    );
+    assert_eq!(
+        check_resumable(&input, || digit()
+            .sep_by_times::<_, ()>(token(','), 2)
+            .collect::<Vec<_>>()),
+        Ok(vec![1, 2]),
+    );
+    assert_eq!(
+        check_resumable(&input, || {
+            digit()
+                .sep_by_end_times::<_, ()>(token(','), 3)
+                .collect::<Vec<_>>()
+        }),
+        Ok(vec![1, 2, 3]),
+    );
+    assert!(check_resumable(&input, || digit()
+        .sep_by_times::<_, ()>(token(','), 4)
+        .count())
+    .is_err());
+    assert!(check_resumable(&input, || digit()
+        .sep_by_end_times::<_, ()>(token(','), 4)
+        .count())
+    .is_err());
+}
+
+#[test]
+fn flat() {
+    let input = chars("12;34x");
+    let pair = || digit().times(2);
+    assert_eq!(
+        check_resumable(&input, || pair().flat_repeat(..).collect::<Vec<_>>()),
+        Ok(vec![1, 2]),
+    );
+    assert_eq!(
+        check_resumable(&input, || pair().flat_times(1).collect::<Vec<_>>()),
+        Ok(vec![1, 2]),
+    );
+    assert_eq!(
+        check_resumable(&input, || pair()
+            .flat_sep_by(token(';'), ..)
+            .collect::<Vec<_>>()),
+        Ok(vec![1, 2, 3, 4]),
+    );
+    assert_eq!(
+        check_resumable(&input, || pair()
+            .flat_sep_by_end(token(';'), ..)
+            .collect::<Vec<_>>()),
+        Ok(vec![1, 2, 3, 4]),
+    );
+    assert_eq!(
+        check_resumable(&input, || {
+            pair().flat_sep_by_times(token(';'), 2).collect::<Vec<_>>()
+        }),
+        Ok(vec![1, 2, 3, 4]),
+    );
+    assert_eq!(
+        check_resumable(&input, || {
+            pair()
+                .flat_sep_by_end_times(token(';'), 2)
+                .collect::<Vec<_>>()
+        }),
+        Ok(vec![1, 2, 3, 4]),
+    );
+    assert_eq!(
+        check_resumable(&input, || pair().flat_until(token(';')).collect::<Vec<_>>()),
+        Ok(vec![1, 2]),
+    );
+}
+
+#[test]
+fn consumers() {
+    let input = chars("1234");
+    assert_eq!(
+        check_resumable(&input, || digit().repeat(..).discard()),
+        Ok(())
+    );
+    assert_eq!(
+        check_resumable(&input, || digit().repeat(..).last()),
+        Ok(Some(4))
+    );
+    assert_eq!(
+        check_resumable(&input, || digit().repeat(..).nth(1)),
+        Ok(Some(2))
+    );
+    assert_eq!(
+        check_resumable(&input, || digit().repeat(..).first()),
+        Ok(Some(1))
+    );
+    assert_eq!(
+        check_resumable(&input, || digit().repeat(..).fill::<2>(3)),
+        Ok(None)
+    );
+    assert_eq!(
+        check_resumable(&input, || digit().repeat(..).fill::<2>(1)),
+        Ok(Some([2, 3])),
+    );
+    assert_eq!(
+        check_resumable(&input, || digit().repeat(..).indices([0, 3])),
+        Ok(Some([1, 4])),
+    );
+    assert_eq!(
+        check_resumable(&input, || digit()
+            .repeat(..)
+            .fold(value(0), |acc, d| acc * 10 + d)),
+        Ok(1234),
+    );
+    assert!(check_resumable(&input, || {
+        digit().repeat(..).try_fold(value(0u32), |acc, d| {
+            acc.checked_mul(100_000)
+                .map(|acc| acc + d)
+                .ok_or("overflow")
+        })
+    })
+    .is_err());
+    assert_eq!(
+        check_resumable(&input, || digit().repeat(..).reduce(|a, b| a + b)),
+        Ok(Some(10)),
+    );
+    assert!(check_resumable(&input, || {
+        digit()
+            .repeat(..)
+            .try_reduce(|a, b| if b < 3 { Ok(a + b) } else { Err("too large") })
+    })
+    .is_err());
+}
+
+#[test]
+fn adapters() {
+    let input = chars("1234");
+    assert_eq!(
+        check_resumable(&input, || digit()
+            .repeat(..)
+            .enumerate()
+            .collect::<Vec<_>>()),
+        Ok(vec![(0, 1), (1, 2), (2, 3), (3, 4)]),
+    );
+    assert_eq!(
+        check_resumable(&input, || digit()
+            .repeat(..)
+            .filter(|d| d % 2 == 0)
+            .collect::<Vec<_>>()),
+        Ok(vec![2, 4]),
+    );
+    assert_eq!(
+        check_resumable(&input, || {
+            digit().repeat(..).map(|d| d * 2).collect::<Vec<_>>()
+        }),
+        Ok(vec![2, 4, 6, 8]),
+    );
+    assert_eq!(
+        check_resumable(&input, || {
+            digit()
+                .repeat(..)
+                .scan(value(0), |sum, d| {
+                    *sum += d;
+                    Some(*sum)
+                })
+                .collect::<Vec<_>>()
+        }),
+        Ok(vec![1, 3, 6, 10]),
+    );
+    assert_eq!(
+        check_resumable(&input, || {
+            digit()
+                .times(2)
+                .collect::<Vec<_>>()
+                .times(2)
+                .flatten()
+                .collect::<Vec<_>>()
+        }),
+        Ok(vec![1, 2, 3, 4]),
+    );
+    assert!(check_resumable(&input, || {
+        digit()
+            .repeat(..)
+            .try_map(|d| if d < 3 { Ok(d) } else { Err("too large") })
+            .count()
+    })
+    .is_err());
+}
+
+#[test]
+fn choices() {
+    let input = chars("12ab");
+    assert_eq!(
+        check_resumable(&input, || {
+            choice_iterable((token('a').prefix(digit().times(2)), digit().times(2)))
+                .collect::<Vec<_>>()
+                .and(any())
+        }),
+        Ok((vec![1, 2], 'a')),
+    );
+    assert_eq!(
+        check_resumable(&input, || {
+            let hex = is_some(|c: char| c.to_digit(16));
+            digit().times(2).chain(hex.times(2)).collect::<Vec<_>>()
+        }),
+        Ok(vec![1, 2, 10, 11]),
+    );
+}
@@ -0,0 +1,14 @@
+//! Checks that parsers give the same results when the input returns `Poll::Pending` anywhere.
+
+mod atomic;
+mod binary;
+mod combinator;
+mod iterable;
+mod slice;
+mod text;
+mod wrapper;
+
+/// Collects characters of `s`, as an input of parsers for `char`.
+pub fn chars(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
@@ -0,0 +1,54 @@
+use somen::parser::slice;
+use somen::prelude::*;
+use somen::testing::check_resumable;
+
+#[test]
+fn tag_tokens() {
+    let input = b"GET /index";
+    assert_eq!(
+        check_resumable(input, || (
+            slice::tag("GET"),
+            token(b' '),
+            slice::tokens(b"/")
+        )),
+        Ok(("GET", b' ', &b"/"[..])),
+    );
+    assert!(check_resumable(input, || (any(), slice::tag("GET"))).is_err());
+    assert!(check_resumable(input, || slice::tokens(b"GET /x")).is_err());
+}
+
+#[test]
+fn spans() {
+    let input = b"  foo123 bar";
+    assert_eq!(
+        check_resumable(input, || (
+            slice::span_of(b" "),
+            slice::take_while1(|b: &u8| b.is_ascii_alphabetic()),
+            slice::take_while(|b: &u8| b.is_ascii_digit()),
+            token(b' '),
+            slice::take_while(|b: &u8| b.is_ascii_digit()),
+        )),
+        Ok((&b"  "[..], &b"foo"[..], &b"123"[..], b' ', &b""[..])),
+    );
+    assert!(check_resumable(input, || slice::take_while1(|b: &u8| b.is_ascii_digit())).is_err());
+}
+
+#[test]
+fn take_until() {
+    let input = b"/* a */ b";
+    assert_eq!(
+        check_resumable(input, || {
+            tokens(b"/*")
+                .prefix(slice::take_until(b"*/"))
+                .skip(slice::tag("*/"))
+                .and(any().repeat(..).count())
+        }),
+        Ok((&b" a "[..], 2)),
+    );
+    assert!(check_resumable(input, || (slice::take(2), slice::take_until(b"/*"))).is_err());
+    assert_eq!(
+        check_resumable(input, || (any(), slice::take(3), any())),
+        Ok((b'/', &b"* a"[..], b' ')),
+    );
+    assert!(check_resumable(input, || slice::take(10)).is_err());
+}
@@ -0,0 +1,99 @@
+use somen::parser::text::{indented_block, string_literal, symbol, trivia, TriviaKind};
+use somen::prelude::*;
+use somen::stream::position::LineCol;
+use somen::testing::{check_resumable, check_resumable_with, Interrupted};
+
+use super::chars;
+
+#[test]
+fn strings() {
+    let input = chars(r#""a\tbé😀" "x""#);
+    assert_eq!(
+        check_resumable(&input, || string_literal()
+            .skip(token(' '))
+            .and(string_literal())),
+        Ok((String::from("a\tb\u{e9}\u{1f600}"), String::from("x"))),
+    );
+
+    let input = chars(r##"r#"a "quoted" \n"#"##);
+    assert_eq!(
+        check_resumable(&input, || {
+            string_literal()
+                .escapes(&[('n', '\n'), ('\\', '\\'), ('"', '"')])
+                .braced_unicode(true)
+                .raw(Some('r'))
+        }),
+        Ok(String::from(r#"a "quoted" \n"#)),
+    );
+
+    let input = chars(r#""ab\q""#);
+    assert!(check_resumable(&input, string_literal).is_err());
+    let input = chars(r#""ab"#);
+    assert!(check_resumable(&input, string_literal).is_err());
+}
+
+#[test]
+fn trivia_symbol() {
+    let input = chars("let /* a /* b */ */ // c\n x # d\n");
+    let ws = || {
+        trivia()
+            .line_comments(&["//", "#"])
+            .block_comment("/*", "*/")
+            .nested(true)
+    };
+    assert_eq!(
+        check_resumable(&input, || ws().symbol("let").prefix(ws().lexeme(tag("x")))),
+        Ok("x"),
+    );
+    assert_eq!(
+        check_resumable(&input, || {
+            symbol("let")
+                .prefix(ws().captured())
+                .map(|pieces| pieces.into_iter().map(|p| p.kind).collect::<Vec<_>>())
+        }),
+        Ok(vec![
+            TriviaKind::BlockComment,
+            TriviaKind::Whitespace,
+            TriviaKind::LineComment,
+            TriviaKind::Whitespace,
+        ]),
+    );
+
+    let input = chars("/* a /* b */");
+    assert!(check_resumable(&input, || ws().skip(eof())).is_err());
+}
+
+#[test]
+fn indentation() {
+    let word = || {
+        is(|c: &char| c.is_alphanumeric())
+            .repeat(1..)
+            .collect::<String>()
+    };
+    let parser = || {
+        position().then(move |pos: LineCol| {
+            word()
+                .skip(token(':'))
+                .and(indented_block(pos.col, word()).collect::<Vec<_>>())
+        })
+    };
+
+    let input = chars("list:\n  a\n\n  b\nnext");
+    assert_eq!(
+        check_resumable_with(
+            |schedule| Interrupted::new(stream::from_slice(&input), schedule).positioned(),
+            parser,
+        ),
+        Ok((
+            String::from("list"),
+            vec![String::from("a"), String::from("b")]
+        )),
+    );
+
+    let input = chars("list:\n  a\n   b");
+    assert!(check_resumable_with(
+        |schedule| Interrupted::new(stream::from_slice(&input), schedule).positioned(),
+        parser,
+    )
+    .is_err());
+}
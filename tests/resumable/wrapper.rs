@@ -0,0 +1,182 @@
+use std::collections::HashSet;
+
+use somen::call;
+use somen::error::Expects;
+use somen::prelude::*;
+use somen::stream::trace::TraceEvent;
+use somen::testing::{check_resumable, check_resumable_with, Interrupted};
+
+use super::chars;
+
+#[test]
+fn map_satisfy() {
+    let input = chars("12x");
+    let digit = || is_some(|c: char| c.to_digit(10));
+    assert_eq!(
+        check_resumable(&input, || (digit(), digit()).map(|(a, b)| a * 10 + b)),
+        Ok(12),
+    );
+    assert!(check_resumable(&input, || {
+        digit().try_map(|d| if d > 1 { Ok(d) } else { Err("a large digit") })
+    })
+    .is_err());
+    assert_eq!(
+        check_resumable(&input, || digit().satisfy::<_, ()>(|d| *d == 1)),
+        Ok(1)
+    );
+    assert!(check_resumable(&input, || digit().satisfy::<_, ()>(|d| *d == 2)).is_err());
+    assert_eq!(
+        check_resumable(&input, || (digit(), digit()).discard().and(any())),
+        Ok(((), 'x')),
+    );
+    assert_eq!(
+        check_resumable(&input, || (digit(), digit()).no_state().and(any())),
+        Ok(((1, 2), 'x')),
+    );
+}
+
+#[test]
+fn errors() {
+    let input = chars("ab");
+    assert!(check_resumable(&input, || tag("ax").expect("ax")).is_err());
+    assert!(check_resumable(&input, || tag("ax").exclusive("ax").or(tag("ab"))).is_err());
+    assert!(check_resumable(&input, || tag("ax").cut().or(tag("ab"))).is_err());
+    assert_eq!(
+        check_resumable(&input, || tag("ax")
+            .exclusive("ax")
+            .rewindable()
+            .or(tag("ab"))),
+        Ok("ab"),
+    );
+    assert!(check_resumable(&input, || tag("ax")
+        .map_err::<_, Expects>(|_| "a keyword".into()))
+    .is_err());
+}
+
+#[test]
+fn positions() {
+    let input = chars("abc");
+    assert_eq!(
+        check_resumable(&input, || (any(), tag("bc").spanned())),
+        Ok(('a', "bc")),
+    );
+    assert_eq!(
+        check_resumable(&input, || (any(), tag("bc").with_position())),
+        Ok(('a', ("bc", 1..3))),
+    );
+    assert!(check_resumable(&input, || (any(), tag("bx").spanned())).is_err());
+}
+
+#[test]
+fn either() {
+    let input = chars("ab");
+    assert_eq!(
+        check_resumable(&input, || {
+            any().then(|c| {
+                if c == 'a' {
+                    token('b').left()
+                } else {
+                    any().right()
+                }
+            })
+        }),
+        Ok('b'),
+    );
+}
+
+#[test]
+fn recursion() {
+    fn nested<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = usize> + 'a {
+        call!(nested)
+            .map(|n| n + 1)
+            .between(token('['), token(']'))
+            .or(value(0))
+    }
+
+    let input = chars("[[[]]]");
+    assert_eq!(check_resumable(&input, nested), Ok(3));
+    let input = chars("[[[]]");
+    assert!(check_resumable(&input, nested).is_err());
+
+    let input = |schedule| Interrupted::new(stream::from_slice(b"[[[]]]"), schedule).limited();
+    assert_eq!(
+        check_resumable_with(input, || {
+            token(b'[')
+                .repeat(..)
+                .count()
+                .nested()
+                .skip(token(b']').repeat(..).discard())
+        }),
+        Ok(3),
+    );
+}
+
+#[test]
+fn context() {
+    let input = |schedule| {
+        Interrupted::new(stream::from_slice("type foo;foo x".as_bytes()), schedule)
+            .with_context(HashSet::new())
+    };
+    let name = || {
+        is(|b: &u8| b.is_ascii_lowercase())
+            .repeat(1..)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        check_resumable_with(input, || {
+            let typedef = tokens(b"type ")
+                .prefix(name())
+                .map_ctx(|name, types: &mut HashSet<Vec<u8>>| types.insert(name))
+                .discard();
+            let decl = name()
+                .satisfy_ctx(|name, types: &mut HashSet<Vec<u8>>| types.contains(name))
+                .skip(token(b' '))
+                .then_ctx(|ty, types: &mut HashSet<Vec<u8>>| {
+                    let len = types.len();
+                    name().map(move |var| (ty.clone(), var, len))
+                });
+            typedef.skip(token(b';')).prefix(decl)
+        }),
+        Ok((b"foo".to_vec(), b"x".to_vec(), 1)),
+    );
+}
+
+#[test]
+fn cst_trace() {
+    #[derive(Clone, Debug, PartialEq)]
+    enum Kind {
+        Hex,
+        Dec,
+    }
+
+    let num = || {
+        tag("0x")
+            .prefix(is(|c: &char| c.is_ascii_hexdigit()).repeat(1..).count())
+            .node(Kind::Hex)
+            .or(is(|c: &char| c.is_ascii_digit())
+                .repeat(1..)
+                .count()
+                .node(Kind::Dec))
+    };
+    let input = chars("0x1f 012");
+    assert_eq!(
+        check_resumable_with(
+            |schedule| Interrupted::new(stream::from_slice(&input), schedule).cst::<Kind>(),
+            || num().skip(token(' ')).and(num()),
+        ),
+        Ok((2, 3)),
+    );
+    assert_eq!(
+        check_resumable_with(
+            |schedule| {
+                Interrupted::new(stream::from_slice(&input), schedule)
+                    .traced(|_: TraceEvent<'_, usize>| {})
+            },
+            || {
+                let num = || is(|c: &char| c.is_ascii_alphanumeric()).repeat(1..).count();
+                num().trace("num").skip(token(' ')).and(num().trace("num"))
+            },
+        ),
+        Ok((4, 3)),
+    );
+}